@@ -0,0 +1,10 @@
+CREATE TABLE
+  handover_task (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    resignation_id INT NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    working_days_before INT UNSIGNED NOT NULL,
+    completed BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at DATETIME NOT NULL,
+    FOREIGN KEY (resignation_id) REFERENCES resignation (id) ON DELETE CASCADE
+  );
@@ -0,0 +1,14 @@
+ALTER TABLE resignation
+ADD COLUMN status VARCHAR(32) NOT NULL DEFAULT 'draft';
+
+CREATE TABLE
+  resignation_status_history (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    resignation_id INT NOT NULL,
+    from_status VARCHAR(32) NOT NULL,
+    to_status VARCHAR(32) NOT NULL,
+    changed_by VARCHAR(255) NOT NULL,
+    comment TEXT,
+    changed_at DATETIME NOT NULL,
+    FOREIGN KEY (resignation_id) REFERENCES resignation (id) ON DELETE CASCADE
+  );
@@ -0,0 +1,12 @@
+CREATE TABLE
+  audit_log (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    operation_name VARCHAR(255) NOT NULL,
+    variables TEXT NOT NULL,
+    actor VARCHAR(255),
+    client_ip VARCHAR(64),
+    succeeded BOOLEAN NOT NULL,
+    errors TEXT,
+    created_at DATETIME NOT NULL,
+    INDEX audit_log_created_at (created_at)
+  );
@@ -0,0 +1,31 @@
+CREATE TABLE
+  resignation_revision (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    resignation_id INT NOT NULL,
+    revision INT UNSIGNED NOT NULL,
+    retirement_date DATE NOT NULL,
+    remaining_paid_leave_days INT UNSIGNED NOT NULL,
+    vacation_start_date DATE,
+    created_at DATETIME NOT NULL,
+    UNIQUE (resignation_id, revision),
+    FOREIGN KEY (resignation_id) REFERENCES resignation (id) ON DELETE CASCADE
+  );
+
+INSERT INTO
+  resignation_revision (
+    resignation_id,
+    revision,
+    retirement_date,
+    remaining_paid_leave_days,
+    vacation_start_date,
+    created_at
+  )
+SELECT
+  id,
+  1,
+  retirement_date,
+  remaining_paid_leave_days,
+  NULL,
+  created_at
+FROM
+  resignation;
@@ -0,0 +1,10 @@
+CREATE TABLE
+  idempotency_key (
+    idempotency_key VARCHAR(255) PRIMARY KEY,
+    request VARCHAR(255) NOT NULL,
+    resignation_id INT NOT NULL,
+    created_at DATETIME NOT NULL,
+    expires_at DATETIME NOT NULL,
+    INDEX idempotency_key_expires_at (expires_at),
+    FOREIGN KEY (resignation_id) REFERENCES resignation (id) ON DELETE CASCADE
+  );
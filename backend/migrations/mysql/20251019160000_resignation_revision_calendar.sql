@@ -0,0 +1,8 @@
+ALTER TABLE resignation_revision
+ADD COLUMN calendar VARCHAR(16) NOT NULL DEFAULT 'JP';
+
+-- これまでのリビジョンは、今の退職のカレンダーで記録したものとみなす
+UPDATE resignation_revision
+JOIN resignation ON resignation.id = resignation_revision.resignation_id
+SET
+  resignation_revision.calendar = resignation.calendar;
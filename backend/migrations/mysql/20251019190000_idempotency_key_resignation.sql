@@ -0,0 +1,17 @@
+-- 同じキーで再送されたときに登録時の内容を返せるよう、登録した退職の内容を一緒に保存する
+ALTER TABLE idempotency_key
+ADD COLUMN retirement_date DATE NOT NULL DEFAULT '1970-01-01',
+ADD COLUMN remaining_paid_leave_days INT UNSIGNED NOT NULL DEFAULT 0,
+ADD COLUMN calendar VARCHAR(16) NOT NULL DEFAULT 'JP',
+ADD COLUMN status VARCHAR(32) NOT NULL DEFAULT 'draft',
+ADD COLUMN resignation_created_at DATETIME NOT NULL DEFAULT '1970-01-01 00:00:00';
+
+-- これまでのキーは、今の退職の内容で登録したものとみなす
+UPDATE idempotency_key
+JOIN resignation ON resignation.id = idempotency_key.resignation_id
+SET
+  idempotency_key.retirement_date = resignation.retirement_date,
+  idempotency_key.remaining_paid_leave_days = resignation.remaining_paid_leave_days,
+  idempotency_key.calendar = resignation.calendar,
+  idempotency_key.status = resignation.status,
+  idempotency_key.resignation_created_at = resignation.created_at;
@@ -0,0 +1,7 @@
+CREATE TABLE
+  resignation (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    retirement_date DATE NOT NULL,
+    remaining_paid_leave_days INTEGER NOT NULL CHECK (remaining_paid_leave_days >= 0),
+    created_at TIMESTAMP NOT NULL
+  );
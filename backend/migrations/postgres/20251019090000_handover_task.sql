@@ -0,0 +1,9 @@
+CREATE TABLE
+  handover_task (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    resignation_id INTEGER NOT NULL REFERENCES resignation (id) ON DELETE CASCADE,
+    title VARCHAR(255) NOT NULL,
+    working_days_before INTEGER NOT NULL CHECK (working_days_before >= 0),
+    completed BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL
+  );
@@ -0,0 +1,13 @@
+CREATE TABLE
+  audit_log (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    operation_name VARCHAR(255) NOT NULL,
+    variables TEXT NOT NULL,
+    actor VARCHAR(255),
+    client_ip VARCHAR(64),
+    succeeded BOOLEAN NOT NULL,
+    errors TEXT,
+    created_at TIMESTAMP NOT NULL
+  );
+
+CREATE INDEX audit_log_created_at ON audit_log (created_at);
@@ -0,0 +1,30 @@
+CREATE TABLE
+  resignation_revision (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    resignation_id INTEGER NOT NULL REFERENCES resignation (id) ON DELETE CASCADE,
+    revision INTEGER NOT NULL CHECK (revision > 0),
+    retirement_date DATE NOT NULL,
+    remaining_paid_leave_days INTEGER NOT NULL CHECK (remaining_paid_leave_days >= 0),
+    vacation_start_date DATE,
+    created_at TIMESTAMP NOT NULL,
+    UNIQUE (resignation_id, revision)
+  );
+
+INSERT INTO
+  resignation_revision (
+    resignation_id,
+    revision,
+    retirement_date,
+    remaining_paid_leave_days,
+    vacation_start_date,
+    created_at
+  )
+SELECT
+  id,
+  1,
+  retirement_date,
+  remaining_paid_leave_days,
+  NULL,
+  created_at
+FROM
+  resignation;
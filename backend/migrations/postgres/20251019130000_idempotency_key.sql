@@ -0,0 +1,10 @@
+CREATE TABLE
+  idempotency_key (
+    idempotency_key VARCHAR(255) PRIMARY KEY,
+    request VARCHAR(255) NOT NULL,
+    resignation_id INTEGER NOT NULL REFERENCES resignation (id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL,
+    expires_at TIMESTAMP NOT NULL
+  );
+
+CREATE INDEX idempotency_key_expires_at ON idempotency_key (expires_at);
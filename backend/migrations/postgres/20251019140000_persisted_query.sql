@@ -0,0 +1,6 @@
+CREATE TABLE
+  persisted_query (
+    sha256_hash CHAR(64) PRIMARY KEY,
+    query TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL
+  );
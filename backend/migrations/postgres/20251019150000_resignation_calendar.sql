@@ -0,0 +1,2 @@
+ALTER TABLE resignation
+ADD COLUMN calendar VARCHAR(16) NOT NULL DEFAULT 'JP';
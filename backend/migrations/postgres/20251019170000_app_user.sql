@@ -0,0 +1,7 @@
+CREATE TABLE
+  app_user (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    is_admin BOOLEAN NOT NULL,
+    created_at TIMESTAMP NOT NULL
+  );
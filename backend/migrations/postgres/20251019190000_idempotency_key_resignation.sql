@@ -0,0 +1,20 @@
+-- 同じキーで再送されたときに登録時の内容を返せるよう、登録した退職の内容を一緒に保存する
+ALTER TABLE idempotency_key
+ADD COLUMN retirement_date DATE NOT NULL DEFAULT '1970-01-01',
+ADD COLUMN remaining_paid_leave_days INTEGER NOT NULL DEFAULT 0 CHECK (remaining_paid_leave_days >= 0),
+ADD COLUMN calendar VARCHAR(16) NOT NULL DEFAULT 'JP',
+ADD COLUMN status VARCHAR(32) NOT NULL DEFAULT 'draft',
+ADD COLUMN resignation_created_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:00';
+
+-- これまでのキーは、今の退職の内容で登録したものとみなす
+UPDATE idempotency_key
+SET
+  retirement_date = resignation.retirement_date,
+  remaining_paid_leave_days = resignation.remaining_paid_leave_days,
+  calendar = resignation.calendar,
+  status = resignation.status,
+  resignation_created_at = resignation.created_at
+FROM
+  resignation
+WHERE
+  resignation.id = idempotency_key.resignation_id;
@@ -0,0 +1,13 @@
+ALTER TABLE resignation
+ADD COLUMN status VARCHAR(32) NOT NULL DEFAULT 'draft';
+
+CREATE TABLE
+  resignation_status_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resignation_id INTEGER NOT NULL REFERENCES resignation (id) ON DELETE CASCADE,
+    from_status VARCHAR(32) NOT NULL,
+    to_status VARCHAR(32) NOT NULL,
+    changed_by VARCHAR(255) NOT NULL,
+    comment TEXT,
+    changed_at DATETIME NOT NULL
+  );
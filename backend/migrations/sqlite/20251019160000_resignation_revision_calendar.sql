@@ -0,0 +1,14 @@
+ALTER TABLE resignation_revision
+ADD COLUMN calendar VARCHAR(16) NOT NULL DEFAULT 'JP';
+
+-- これまでのリビジョンは、今の退職のカレンダーで記録したものとみなす
+UPDATE resignation_revision
+SET
+  calendar = (
+    SELECT
+      calendar
+    FROM
+      resignation
+    WHERE
+      resignation.id = resignation_revision.resignation_id
+  );
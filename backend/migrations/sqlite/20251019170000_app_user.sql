@@ -0,0 +1,7 @@
+CREATE TABLE
+  app_user (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    is_admin BOOLEAN NOT NULL,
+    created_at DATETIME NOT NULL
+  );
@@ -0,0 +1,8 @@
+CREATE TABLE
+  holiday (
+    calendar VARCHAR(16) NOT NULL,
+    holiday_date DATE NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    source VARCHAR(16) NOT NULL,
+    PRIMARY KEY (calendar, holiday_date)
+  );
@@ -0,0 +1,2 @@
+-- X-Actor は認証していない自己申告の名前なので、列名でそれと分かるようにする
+ALTER TABLE audit_log RENAME COLUMN actor TO claimed_actor;
@@ -0,0 +1,37 @@
+-- 同じキーで再送されたときに登録時の内容を返せるよう、登録した退職の内容を一緒に保存する
+ALTER TABLE idempotency_key
+ADD COLUMN retirement_date DATE NOT NULL DEFAULT '1970-01-01';
+
+ALTER TABLE idempotency_key
+ADD COLUMN remaining_paid_leave_days INTEGER NOT NULL DEFAULT 0 CHECK (remaining_paid_leave_days >= 0);
+
+ALTER TABLE idempotency_key
+ADD COLUMN calendar VARCHAR(16) NOT NULL DEFAULT 'JP';
+
+ALTER TABLE idempotency_key
+ADD COLUMN status VARCHAR(32) NOT NULL DEFAULT 'draft';
+
+ALTER TABLE idempotency_key
+ADD COLUMN resignation_created_at DATETIME NOT NULL DEFAULT '1970-01-01 00:00:00';
+
+-- これまでのキーは、今の退職の内容で登録したものとみなす
+UPDATE idempotency_key
+SET
+  (
+    retirement_date,
+    remaining_paid_leave_days,
+    calendar,
+    status,
+    resignation_created_at
+  ) = (
+    SELECT
+      retirement_date,
+      remaining_paid_leave_days,
+      calendar,
+      status,
+      created_at
+    FROM
+      resignation
+    WHERE
+      resignation.id = idempotency_key.resignation_id
+  );
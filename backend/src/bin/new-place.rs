@@ -0,0 +1,26 @@
+use anyhow::Result;
+use backend::cli::calc::{self, CalcArgs};
+use clap::{Parser, Subcommand};
+
+/// DB を使わずに手元で休暇開始日を計算する
+#[derive(Parser)]
+#[command(name = "new-place", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 退職日と残りの有給日数から休暇開始日を計算する
+    Calc(CalcArgs),
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+
+    match Cli::parse().command {
+        Command::Calc(args) => calc::run(args).await,
+    }
+}
@@ -0,0 +1,96 @@
+//! 国・地域ごとの休日のカレンダー。どれもネットワークに繋がらずに規則から計算する
+pub mod germany;
+pub mod japan;
+pub mod united_states;
+
+use std::{collections::BTreeMap, ops::RangeInclusive, str::FromStr};
+
+use chrono::NaiveDate;
+
+use crate::errors::AppError;
+
+/// 計算できる年。日本の春分日・秋分日の近似式に合わせ、どのカレンダーも同じ範囲にする
+pub const SUPPORTED_YEARS: RangeInclusive<i32> = japan::SUPPORTED_YEARS;
+
+/// 休日のカレンダー。ISO 3166 の国コード (地域がある場合は `US-CA` のように ISO 3166-2) で指定する
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, sqlx::Type)]
+pub enum Calendar {
+    #[default]
+    #[sqlx(rename = "JP")]
+    Jp,
+    #[sqlx(rename = "US")]
+    Us,
+    #[sqlx(rename = "US-CA")]
+    UsCa,
+    #[sqlx(rename = "DE")]
+    De,
+    #[sqlx(rename = "DE-BY")]
+    DeBy,
+}
+
+impl Calendar {
+    pub const ALL: [Self; 5] = [Self::Jp, Self::Us, Self::UsCa, Self::De, Self::DeBy];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Jp => "JP",
+            Self::Us => "US",
+            Self::UsCa => "US-CA",
+            Self::De => "DE",
+            Self::DeBy => "DE-BY",
+        }
+    }
+
+    /// `year` 年の祝日。対応していない年は空になる
+    pub fn holidays(self, year: i32) -> BTreeMap<NaiveDate, String> {
+        if !SUPPORTED_YEARS.contains(&year) {
+            return BTreeMap::new();
+        }
+
+        match self {
+            Self::Jp => japan::holidays(year),
+            Self::Us => united_states::federal_holidays(year),
+            Self::UsCa => united_states::california_holidays(year),
+            Self::De => germany::national_holidays(year),
+            Self::DeBy => germany::bavaria_holidays(year),
+        }
+    }
+
+    /// `years` の各年の祝日をまとめたもの
+    pub fn holidays_in(self, years: RangeInclusive<i32>) -> BTreeMap<NaiveDate, String> {
+        years.flat_map(|year| self.holidays(year)).collect()
+    }
+}
+
+impl FromStr for Calendar {
+    type Err = AppError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|calendar| calendar.code().eq_ignore_ascii_case(code))
+            .ok_or_else(|| AppError::UnknownCalendar {
+                code: code.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!("JP".parse::<Calendar>().unwrap(), Calendar::Jp);
+        assert_eq!("us-ca".parse::<Calendar>().unwrap(), Calendar::UsCa);
+        assert!("FR".parse::<Calendar>().is_err());
+    }
+
+    #[test]
+    fn holidays_カレンダーごとに祝日が異なること() {
+        let date = NaiveDate::from_ymd_opt(2025, 7, 4).unwrap();
+
+        assert!(Calendar::Us.holidays(2025).contains_key(&date));
+        assert!(!Calendar::Jp.holidays(2025).contains_key(&date));
+    }
+}
@@ -0,0 +1,113 @@
+//! ドイツの全国の祝日と、バイエルン州の祝日
+//!
+//! ドイツには振替休日がないので、土日に当たってもそのままにする。
+
+use std::collections::BTreeMap;
+
+use chrono::{Days, NaiveDate};
+
+/// 全国の祝日
+pub fn national_holidays(year: i32) -> BTreeMap<NaiveDate, String> {
+    let date = |month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let easter = easter_sunday(year);
+    let mut holidays = vec![
+        (date(1, 1), "Neujahr"),
+        (after_easter(easter, -2), "Karfreitag"),
+        (after_easter(easter, 1), "Ostermontag"),
+        (date(5, 1), "Tag der Arbeit"),
+        (after_easter(easter, 39), "Christi Himmelfahrt"),
+        (after_easter(easter, 50), "Pfingstmontag"),
+        (date(10, 3), "Tag der Deutschen Einheit"),
+        (date(12, 25), "1. Weihnachtstag"),
+        (date(12, 26), "2. Weihnachtstag"),
+    ];
+    // 宗教改革 500 年の年だけ全国の祝日になった
+    if year == 2017 {
+        holidays.push((date(10, 31), "Reformationstag"));
+    }
+
+    holidays
+        .into_iter()
+        .map(|(date, name)| (date, name.to_string()))
+        .collect()
+}
+
+/// バイエルン州の祝日。聖母被昇天祭はカトリックが多数の市町村だけの祝日だが、州の大半が該当するので含める
+pub fn bavaria_holidays(year: i32) -> BTreeMap<NaiveDate, String> {
+    let date = |month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let mut holidays = national_holidays(year);
+    holidays.extend(
+        [
+            (date(1, 6), "Heilige Drei Könige"),
+            (after_easter(easter_sunday(year), 60), "Fronleichnam"),
+            (date(8, 15), "Mariä Himmelfahrt"),
+            (date(11, 1), "Allerheiligen"),
+        ]
+        .map(|(date, name)| (date, name.to_string())),
+    );
+
+    holidays
+}
+
+/// 復活祭 (グレゴリオ暦)。Anonymous Gregorian algorithm で求める
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+fn after_easter(easter: NaiveDate, days: i64) -> NaiveDate {
+    if days < 0 {
+        easter - Days::new(days.unsigned_abs())
+    } else {
+        easter + Days::new(days as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn easter_sunday() {
+        assert_eq!(super::easter_sunday(2024), date("2024-03-31"));
+        assert_eq!(super::easter_sunday(2025), date("2025-04-20"));
+        assert_eq!(super::easter_sunday(2026), date("2026-04-05"));
+    }
+
+    #[test]
+    fn national_holidays() {
+        let holidays = super::national_holidays(2025);
+
+        assert_eq!(holidays[&date("2025-04-18")], "Karfreitag");
+        assert_eq!(holidays[&date("2025-05-29")], "Christi Himmelfahrt");
+        assert_eq!(holidays[&date("2025-06-09")], "Pfingstmontag");
+        assert_eq!(holidays.len(), 9);
+    }
+
+    #[test]
+    fn bavaria_holidays() {
+        let holidays = super::bavaria_holidays(2025);
+
+        assert_eq!(holidays[&date("2025-06-19")], "Fronleichnam");
+        assert_eq!(holidays[&date("2025-01-06")], "Heilige Drei Könige");
+        assert_eq!(holidays.len(), 13);
+    }
+}
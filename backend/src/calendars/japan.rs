@@ -0,0 +1,238 @@
+//! 日本の国民の祝日を、祝日法の規則から計算する。ネットワークに繋がらなくても、
+//! holidays-jp が扱っていない年でも使える。
+//!
+//! 春分日と秋分日は国立天文台の暦要項に基づく近似式で求めるため、対応するのは 2000〜2099 年。
+
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+pub const SUPPORTED_YEARS: RangeInclusive<i32> = 2000..=2099;
+
+/// 振替休日の名前。holidays-jp に合わせる
+const SUBSTITUTE_SUFFIX: &str = "振替休日";
+/// 祝日に挟まれた平日 (国民の休日) の名前
+const SANDWICHED_NAME: &str = "国民の休日";
+
+/// `year` 年の祝日。対応していない年は空になる
+pub fn holidays(year: i32) -> BTreeMap<NaiveDate, String> {
+    if !SUPPORTED_YEARS.contains(&year) {
+        return BTreeMap::new();
+    }
+
+    let mut holidays = national_holidays(year)
+        .into_iter()
+        .map(|(date, name)| (date, name.to_string()))
+        .collect::<BTreeMap<_, _>>();
+    let national = holidays.clone();
+    for (date, name) in &national {
+        if let Some(substitute) = substitute_holiday(year, &national, *date) {
+            holidays.insert(substitute, format!("{name} {SUBSTITUTE_SUFFIX}"));
+        }
+    }
+    for date in sandwiched_days(year, &national) {
+        holidays
+            .entry(date)
+            .or_insert_with(|| SANDWICHED_NAME.to_string());
+    }
+
+    holidays
+}
+
+/// `years` の各年の祝日をまとめたもの
+pub fn holidays_in(years: RangeInclusive<i32>) -> BTreeMap<NaiveDate, String> {
+    years.flat_map(holidays).collect()
+}
+
+/// 祝日法第 2 条の「国民の祝日」。振替休日と国民の休日は含まない
+fn national_holidays(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let date = |month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let mut holidays = vec![
+        (date(1, 1), "元日"),
+        (nth_monday(year, 1, 2), "成人の日"),
+        (date(2, 11), "建国記念の日"),
+        (date(3, vernal_equinox_day(year)), "春分の日"),
+        (date(5, 3), "憲法記念日"),
+        (date(5, 5), "こどもの日"),
+        (date(9, autumnal_equinox_day(year)), "秋分の日"),
+        (date(11, 3), "文化の日"),
+        (date(11, 23), "勤労感謝の日"),
+    ];
+
+    // 天皇誕生日
+    match year {
+        ..=2018 => holidays.push((date(12, 23), "天皇誕生日")),
+        2019 => {}
+        2020.. => holidays.push((date(2, 23), "天皇誕生日")),
+    }
+    // 2007 年に 4 月 29 日が昭和の日になり、みどりの日は 5 月 4 日に移った
+    if year >= 2007 {
+        holidays.push((date(4, 29), "昭和の日"));
+        holidays.push((date(5, 4), "みどりの日"));
+    } else {
+        holidays.push((date(4, 29), "みどりの日"));
+    }
+    // 東京オリンピック・パラリンピックの年は、海の日・スポーツの日・山の日が移動した
+    match year {
+        2020 => holidays.extend([
+            (date(7, 23), "海の日"),
+            (date(7, 24), "スポーツの日"),
+            (date(8, 10), "山の日"),
+        ]),
+        2021 => holidays.extend([
+            (date(7, 22), "海の日"),
+            (date(7, 23), "スポーツの日"),
+            (date(8, 8), "山の日"),
+        ]),
+        _ => {
+            let marine_day = if year >= 2003 {
+                nth_monday(year, 7, 3)
+            } else {
+                date(7, 20)
+            };
+            holidays.push((marine_day, "海の日"));
+            if year >= 2016 {
+                holidays.push((date(8, 11), "山の日"));
+            }
+            let sports_day_name = if year >= 2020 {
+                "スポーツの日"
+            } else {
+                "体育の日"
+            };
+            holidays.push((nth_monday(year, 10, 2), sports_day_name));
+        }
+    }
+    let respect_for_the_aged_day = if year >= 2003 {
+        nth_monday(year, 9, 3)
+    } else {
+        date(9, 15)
+    };
+    holidays.push((respect_for_the_aged_day, "敬老の日"));
+    // 天皇の即位に伴う一度限りの祝日
+    if year == 2019 {
+        holidays.push((date(5, 1), "天皇の即位の日"));
+        holidays.push((date(10, 22), "即位礼正殿の儀の行われる日"));
+    }
+
+    holidays
+}
+
+/// 祝日が日曜日に当たるときの振替休日
+///
+/// 2006 年までは翌日の月曜日だけ、2007 年以降は祝日でない最初の日になる。
+fn substitute_holiday(
+    year: i32,
+    national: &BTreeMap<NaiveDate, String>,
+    date: NaiveDate,
+) -> Option<NaiveDate> {
+    if date.weekday() != Weekday::Sun {
+        return None;
+    }
+
+    let mut substitute = date.succ_opt()?;
+    if year < 2007 {
+        return (!national.contains_key(&substitute)).then_some(substitute);
+    }
+    while national.contains_key(&substitute) {
+        substitute = substitute.succ_opt()?;
+    }
+
+    Some(substitute)
+}
+
+/// 前日と翌日が国民の祝日である、祝日でない日 (国民の休日)
+///
+/// 2006 年までは日曜日を除く。
+fn sandwiched_days(year: i32, national: &BTreeMap<NaiveDate, String>) -> Vec<NaiveDate> {
+    national
+        .keys()
+        .filter_map(|date| {
+            let next = date.succ_opt()?;
+            let day_after_next = next.succ_opt()?;
+            let is_sandwiched = !national.contains_key(&next)
+                && national.contains_key(&day_after_next)
+                && (year >= 2007 || next.weekday() != Weekday::Sun);
+
+            is_sandwiched.then_some(next)
+        })
+        .collect()
+}
+
+/// `month` 月の第 `n` 月曜日 (ハッピーマンデー)
+fn nth_monday(year: i32, month: u32, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Mon, n).unwrap()
+}
+
+/// 3 月の春分日
+fn vernal_equinox_day(year: i32) -> u32 {
+    equinox_day(20.8431, year)
+}
+
+/// 9 月の秋分日
+fn autumnal_equinox_day(year: i32) -> u32 {
+    equinox_day(23.2488, year)
+}
+
+fn equinox_day(base: f64, year: i32) -> u32 {
+    let elapsed = f64::from(year - 1980);
+
+    (base + 0.242194 * elapsed - (elapsed / 4.0).floor()).floor() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fs};
+
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn holidays_api_のデータと一致すること() {
+        // holidays-jp の 2019〜2026 年分
+        let api: HashMap<String, String> = serde_json::from_str(
+            &fs::read_to_string("src/fixtures/holiday/holidays_jp.json").unwrap(),
+        )
+        .unwrap();
+        let mut expected = api.keys().map(|key| date(key)).collect::<Vec<_>>();
+        expected.sort();
+
+        let actual = holidays_in(2019..=2026).into_keys().collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn holidays_振替休日と国民の休日() {
+        let holidays = holidays(2026);
+
+        assert_eq!(holidays[&date("2026-05-06")], "憲法記念日 振替休日");
+        assert_eq!(holidays[&date("2026-09-22")], "国民の休日");
+    }
+
+    #[test]
+    fn holidays_2006年までは振替休日を月曜日に限ること() {
+        let holidays = holidays(2003);
+
+        // 2003 年 11 月 23 日 (日) の振替は 24 日 (月)
+        assert_eq!(holidays[&date("2003-11-24")], "勤労感謝の日 振替休日");
+        // 2003 年 5 月 4 日は日曜日なので国民の休日にならない
+        assert!(!holidays.contains_key(&date("2003-05-04")));
+    }
+
+    #[test]
+    fn holidays_対応していない年の場合_空になること() {
+        assert!(holidays(1999).is_empty());
+        assert!(holidays(2100).is_empty());
+    }
+
+    #[test]
+    fn equinox_day() {
+        assert_eq!(vernal_equinox_day(2024), 20);
+        assert_eq!(autumnal_equinox_day(2024), 22);
+        assert_eq!(vernal_equinox_day(2099), 20);
+        assert_eq!(autumnal_equinox_day(2099), 23);
+    }
+}
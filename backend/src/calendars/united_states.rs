@@ -0,0 +1,150 @@
+//! アメリカの連邦の祝日と、カリフォルニア州の休日
+//!
+//! 日付が決まっている祝日が土曜日に当たるときは前の金曜日、日曜日に当たるときは翌日の月曜日を
+//! 代わりの休日 (observed) にする。
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+/// 代わりの休日の名前に付ける
+const OBSERVED_SUFFIX: &str = "(observed)";
+
+/// 連邦の祝日 (5 U.S.C. 6103)
+pub fn federal_holidays(year: i32) -> BTreeMap<NaiveDate, String> {
+    let mut holidays = BTreeMap::new();
+    for (date, name) in fixed_federal_holidays(year) {
+        insert_with_observed(&mut holidays, date, name);
+    }
+    holidays.extend(
+        [
+            (
+                nth_weekday(year, 1, Weekday::Mon, 3),
+                "Martin Luther King Jr. Day",
+            ),
+            (
+                nth_weekday(year, 2, Weekday::Mon, 3),
+                "Washington's Birthday",
+            ),
+            (last_weekday(year, 5, Weekday::Mon), "Memorial Day"),
+            (nth_weekday(year, 9, Weekday::Mon, 1), "Labor Day"),
+            (nth_weekday(year, 10, Weekday::Mon, 2), "Columbus Day"),
+            (thanksgiving_day(year), "Thanksgiving Day"),
+        ]
+        .map(|(date, name)| (date, name.to_string())),
+    );
+
+    holidays
+}
+
+/// カリフォルニア州の休日。連邦の祝日から Columbus Day を除き、州の休日を加える
+pub fn california_holidays(year: i32) -> BTreeMap<NaiveDate, String> {
+    let mut holidays = federal_holidays(year);
+    holidays.remove(&nth_weekday(year, 10, Weekday::Mon, 2));
+    insert_with_observed(
+        &mut holidays,
+        NaiveDate::from_ymd_opt(year, 3, 31).unwrap(),
+        "Cesar Chavez Day",
+    );
+    if let Some(day_after) = thanksgiving_day(year).succ_opt() {
+        holidays.insert(day_after, "Day after Thanksgiving".to_string());
+    }
+
+    holidays
+}
+
+fn fixed_federal_holidays(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let date = |month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let mut holidays = vec![
+        (date(1, 1), "New Year's Day"),
+        (date(7, 4), "Independence Day"),
+        (date(11, 11), "Veterans Day"),
+        (date(12, 25), "Christmas Day"),
+    ];
+    // 2021 年に制定された
+    if year >= 2021 {
+        holidays.push((date(6, 19), "Juneteenth National Independence Day"));
+    }
+
+    holidays
+}
+
+fn insert_with_observed(holidays: &mut BTreeMap<NaiveDate, String>, date: NaiveDate, name: &str) {
+    let observed = match date.weekday() {
+        Weekday::Sat => date.checked_sub_days(Days::new(1)),
+        Weekday::Sun => date.checked_add_days(Days::new(1)),
+        _ => None,
+    };
+    if let Some(observed) = observed {
+        holidays.insert(observed, format!("{name} {OBSERVED_SUFFIX}"));
+    }
+    holidays.insert(date, name.to_string());
+}
+
+/// 11 月の第 4 木曜日
+fn thanksgiving_day(year: i32) -> NaiveDate {
+    nth_weekday(year, 11, Weekday::Thu, 4)
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).unwrap()
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn federal_holidays() {
+        let holidays = super::federal_holidays(2025);
+
+        assert_eq!(
+            holidays.keys().copied().collect::<Vec<_>>(),
+            [
+                "2025-01-01",
+                "2025-01-20",
+                "2025-02-17",
+                "2025-05-26",
+                "2025-06-19",
+                "2025-07-04",
+                "2025-09-01",
+                "2025-10-13",
+                "2025-11-11",
+                "2025-11-27",
+                "2025-12-25",
+            ]
+            .map(date)
+        );
+    }
+
+    #[test]
+    fn federal_holidays_土日に当たる場合_前後の平日も休みになること() {
+        let holidays = super::federal_holidays(2026);
+
+        // 2026 年 7 月 4 日は土曜日
+        assert_eq!(holidays[&date("2026-07-03")], "Independence Day (observed)");
+        // 2022 年のクリスマスは日曜日
+        assert_eq!(
+            super::federal_holidays(2022)[&date("2022-12-26")],
+            "Christmas Day (observed)"
+        );
+    }
+
+    #[test]
+    fn california_holidays() {
+        let holidays = super::california_holidays(2025);
+
+        assert_eq!(holidays[&date("2025-03-31")], "Cesar Chavez Day");
+        assert_eq!(holidays[&date("2025-11-28")], "Day after Thanksgiving");
+        assert!(!holidays.contains_key(&date("2025-10-13")));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod calc;
+pub mod migrate;
+pub mod schema;
+pub mod seed;
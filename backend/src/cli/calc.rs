@@ -0,0 +1,261 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, Weekday};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+use crate::{
+    calendars::Calendar,
+    infrastructure::config::{HolidayProvider, HolidaysConfig},
+    models::{
+        holiday::{Holiday, HolidayMap},
+        resignation::{Resignation, ResignationStatus},
+    },
+    utils::time::now,
+};
+
+#[derive(Debug, Args)]
+pub struct CalcArgs {
+    /// 退職日 (YYYY-MM-DD)
+    #[arg(long = "retire")]
+    pub retirement_date: NaiveDate,
+    /// 残りの有給日数
+    #[arg(long = "days")]
+    pub remaining_paid_leave_days: u32,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+    /// 休日のカレンダー (JP, US-CA, DE-BY など)
+    #[arg(long, default_value = "JP", value_parser = str::parse::<Calendar>)]
+    pub calendar: Calendar,
+    #[arg(long, env = "HOLIDAYS_PROVIDER", value_enum, default_value_t = HolidayProvider::Api)]
+    pub holidays_provider: HolidayProvider,
+    #[arg(long, env = "HOLIDAYS_BASE_URL")]
+    pub holidays_base_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Calculation {
+    pub retirement_date: NaiveDate,
+    pub remaining_paid_leave_days: u32,
+    pub vacation_start_date: NaiveDate,
+    /// 有給を使う日
+    pub schedule: Vec<NaiveDate>,
+    /// 休暇期間中で有給を使わずに済んだ休日
+    pub skipped_holidays: Vec<SkippedHoliday>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SkippedHoliday {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+impl Calculation {
+    pub fn new(
+        retirement_date: NaiveDate,
+        remaining_paid_leave_days: u32,
+        holidays: &HolidayMap,
+    ) -> Result<Self> {
+        let resignation = Resignation {
+            id: 0,
+            retirement_date,
+            remaining_paid_leave_days,
+            // 休日は引数で受け取るので、カレンダーは計算に使わない
+            calendar: Calendar::default(),
+            status: ResignationStatus::Draft,
+            created_at: now(),
+        };
+        let vacation_start_date = resignation.covered_vacation_start_date(holidays)?;
+        let (schedule, skipped_holidays): (Vec<_>, Vec<_>) = vacation_start_date
+            .iter_days()
+            .take_while(|date| *date <= retirement_date)
+            .partition(|date| holidays.calendar().is_business_day(*date));
+        let skipped_holidays = skipped_holidays
+            .into_iter()
+            .map(|date| SkippedHoliday {
+                date,
+                name: holiday_name(holidays, &date),
+            })
+            .collect();
+
+        Ok(Self {
+            retirement_date,
+            remaining_paid_leave_days,
+            vacation_start_date,
+            schedule,
+            skipped_holidays,
+        })
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "退職日: {}", format_date(&self.retirement_date));
+        let _ = writeln!(text, "残りの有給: {}日", self.remaining_paid_leave_days);
+        let _ = writeln!(
+            text,
+            "休暇開始日: {}",
+            format_date(&self.vacation_start_date)
+        );
+        let _ = writeln!(text);
+        let _ = writeln!(text, "有給を使う日:");
+        for date in &self.schedule {
+            let _ = writeln!(text, "  {}", format_date(date));
+        }
+        let _ = writeln!(text);
+        let _ = writeln!(text, "スキップした休日:");
+        if self.skipped_holidays.is_empty() {
+            let _ = writeln!(text, "  なし");
+        }
+        for skipped_holiday in &self.skipped_holidays {
+            let _ = writeln!(
+                text,
+                "  {} {}",
+                format_date(&skipped_holiday.date),
+                skipped_holiday.name
+            );
+        }
+
+        text
+    }
+}
+
+pub async fn run(args: CalcArgs) -> Result<()> {
+    let default = HolidaysConfig::default();
+    let holidays_config = HolidaysConfig {
+        provider: args.holidays_provider,
+        base_url: args.holidays_base_url.unwrap_or(default.base_url),
+        ..default
+    };
+    let holidays = Holiday::fetch_map(&holidays_config, args.calendar).await?;
+    let calculation = Calculation::new(
+        args.retirement_date,
+        args.remaining_paid_leave_days,
+        &holidays,
+    )?;
+
+    match args.format {
+        Format::Text => print!("{}", calculation.to_text()),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&calculation)?),
+    }
+
+    Ok(())
+}
+
+fn holiday_name(holidays: &HolidayMap, date: &NaiveDate) -> String {
+    match holidays.name(*date) {
+        Some(name) => name.to_string(),
+        None if date.weekday() == Weekday::Sat => "土曜日".to_string(),
+        None => "日曜日".to_string(),
+    }
+}
+
+fn format_date(date: &NaiveDate) -> String {
+    let weekday = match date.weekday() {
+        Weekday::Mon => "月",
+        Weekday::Tue => "火",
+        Weekday::Wed => "水",
+        Weekday::Thu => "木",
+        Weekday::Fri => "金",
+        Weekday::Sat => "土",
+        Weekday::Sun => "日",
+    };
+
+    format!("{date} ({weekday})")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// 2025 年の祝日のデータがあり、土日のほかに休日がない
+    fn no_holidays() -> HolidayMap {
+        HolidayMap::new(HashMap::new(), BTreeSet::from([2025]))
+    }
+
+    #[test]
+    fn new() {
+        let holidays = HolidayMap::new(
+            HashMap::from([(date(2025, 3, 20), "春分の日".to_string())]),
+            BTreeSet::from([2025]),
+        );
+
+        let calculation = Calculation::new(date(2025, 3, 21), 3, &holidays).unwrap();
+
+        assert_eq!(calculation.vacation_start_date, date(2025, 3, 18));
+        assert_eq!(
+            calculation.schedule,
+            vec![date(2025, 3, 18), date(2025, 3, 19), date(2025, 3, 21)]
+        );
+        assert_eq!(
+            calculation.skipped_holidays,
+            vec![SkippedHoliday {
+                date: date(2025, 3, 20),
+                name: "春分の日".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn new_有給がない場合_エラーになること() {
+        let result = Calculation::new(date(2025, 3, 21), 0, &no_holidays());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn new_祝日のデータがない年にかかる場合_エラーになること() {
+        let result = Calculation::new(date(2025, 3, 21), 3, &HolidayMap::default());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn to_text() {
+        let calculation = Calculation::new(date(2025, 3, 31), 2, &no_holidays()).unwrap();
+
+        assert_eq!(
+            calculation.to_text(),
+            "退職日: 2025-03-31 (月)\n\
+             残りの有給: 2日\n\
+             休暇開始日: 2025-03-28 (金)\n\
+             \n\
+             有給を使う日:\n\
+             \x20 2025-03-28 (金)\n\
+             \x20 2025-03-31 (月)\n\
+             \n\
+             スキップした休日:\n\
+             \x20 2025-03-29 (土) 土曜日\n\
+             \x20 2025-03-30 (日) 日曜日\n"
+        );
+    }
+
+    #[test]
+    fn to_json() {
+        let calculation = Calculation::new(date(2025, 3, 31), 1, &no_holidays()).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&calculation).unwrap(),
+            serde_json::json!({
+                "retirementDate": "2025-03-31",
+                "remainingPaidLeaveDays": 1,
+                "vacationStartDate": "2025-03-31",
+                "schedule": ["2025-03-31"],
+                "skippedHolidays": [],
+            })
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+use crate::infrastructure::database::{DbPool, MIGRATOR};
+
+pub async fn run(pool: &DbPool) -> Result<()> {
+    MIGRATOR.run(pool).await?;
+    tracing::info!("マイグレーションを適用しました");
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::infrastructure::app::schema_builder;
+
+/// フロントエンドの型生成に使う SDL
+pub fn sdl() -> String {
+    schema_builder().finish().sdl()
+}
+
+pub fn run() {
+    print!("{}", sdl());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sdl_クエリとミューテーションを含むこと() {
+        let sdl = sdl();
+
+        assert!(sdl.contains("latestResignation"));
+        assert!(sdl.contains("postResignation"));
+    }
+}
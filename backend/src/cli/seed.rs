@@ -0,0 +1,168 @@
+use anyhow::Result;
+use chrono::{Days, NaiveDate};
+
+use crate::{
+    calendars::Calendar,
+    infrastructure::{
+        config::{HolidayProvider, HolidaysConfig},
+        database::DbPool,
+    },
+    models::{
+        handover_task::{HandoverTask, HandoverTaskInput},
+        holiday::{Holiday, HolidayMap},
+        resignation::{Resignation, ResignationInput, ResignationStatus},
+        user::{User, UserInput},
+    },
+    utils::time::now,
+};
+
+const SEED_ACTOR: &str = "seed";
+
+/// デモデータの利用者。名前は `X-Actor` で名乗る名前にする
+const SEED_USERS: [(&str, bool); 3] = [("yamada", false), ("suzuki", false), ("admin", true)];
+
+/// 画面を触って確かめるためのデモデータを入れる。既に退職届がある場合は何もしない
+///
+/// 祝日は外部の API に繋がなくても入れられるよう、同梱した規則から計算する。
+/// 会社の休日は設定ファイルのものを使う。
+pub async fn run(pool: &DbPool, holidays_config: &HolidaysConfig) -> Result<()> {
+    if !Resignation::fetch_all(pool).await?.is_empty() {
+        tracing::info!("既にデータがあるため、デモデータは投入しませんでした");
+        return Ok(());
+    }
+
+    for (name, is_admin) in SEED_USERS {
+        User::insert(
+            pool,
+            &UserInput {
+                name: name.to_string(),
+                is_admin,
+            },
+        )
+        .await?;
+    }
+
+    let holidays_config = HolidaysConfig {
+        provider: HolidayProvider::Offline,
+        ..holidays_config.clone()
+    };
+    let today = now().date();
+    let holidays = Holiday::fetch_between(
+        &holidays_config,
+        Calendar::Jp,
+        today,
+        days_after(today, 365),
+    )
+    .await?;
+    Holiday::store(pool, Calendar::Jp, &holidays).await?;
+    let holiday_map = Holiday::fetch_map(&holidays_config, Calendar::Jp).await?;
+
+    let draft = seed_resignation(pool, &holiday_map, days_after(today, 90), 20).await?;
+    for (title, working_days_before) in [
+        ("引き継ぎ資料の作成", 10),
+        ("取引先への挨拶", 5),
+        ("アカウントの移管", 1),
+    ] {
+        HandoverTask::insert(
+            pool,
+            &HandoverTaskInput {
+                resignation_id: draft.id,
+                title: title.to_string(),
+                working_days_before,
+            },
+        )
+        .await?;
+    }
+
+    let submitted = seed_resignation(pool, &holiday_map, days_after(today, 45), 10).await?;
+    Resignation::transition(
+        pool,
+        submitted.id,
+        ResignationStatus::Submitted,
+        SEED_ACTOR,
+        Some("デモデータ"),
+    )
+    .await?;
+
+    tracing::info!("デモデータを投入しました");
+
+    Ok(())
+}
+
+async fn seed_resignation(
+    pool: &DbPool,
+    holidays: &HolidayMap,
+    retirement_date: NaiveDate,
+    remaining_paid_leave_days: u32,
+) -> Result<Resignation> {
+    let input = ResignationInput {
+        retirement_date,
+        remaining_paid_leave_days,
+        calendar: Calendar::Jp,
+    };
+
+    Ok(Resignation::insert(pool, &input, Some(holidays)).await?)
+}
+
+fn days_after(date: NaiveDate, days: u64) -> NaiveDate {
+    date.checked_add_days(Days::new(days)).unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{holiday::HolidaySource, resignation_revision::ResignationRevision};
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn run_デモデータを投入すること(pool: DbPool) {
+        run(&pool, &HolidaysConfig::default()).await.unwrap();
+
+        let mut resignations = Resignation::fetch_all(&pool).await.unwrap();
+        resignations.sort_by_key(|resignation| resignation.retirement_date);
+        assert_eq!(resignations.len(), 2);
+        assert_eq!(resignations[0].status, ResignationStatus::Submitted);
+        assert_eq!(resignations[1].status, ResignationStatus::Draft);
+        let handover_tasks = HandoverTask::fetch_by_resignation_id(&pool, resignations[1].id)
+            .await
+            .unwrap();
+        assert_eq!(handover_tasks.len(), 3);
+        // 祝日を考慮した休暇開始日をリビジョンに記録する
+        let revisions = ResignationRevision::fetch_by_resignation_id(&pool, resignations[1].id)
+            .await
+            .unwrap();
+        assert!(revisions[0].vacation_start_date.is_some());
+
+        let users = User::fetch_all(&pool).await.unwrap();
+        assert_eq!(
+            users
+                .iter()
+                .map(|user| (user.name.as_str(), user.is_admin))
+                .collect::<Vec<_>>(),
+            SEED_USERS.to_vec()
+        );
+        let holidays = Holiday::fetch_stored(&pool, Calendar::Jp).await.unwrap();
+        assert!(
+            holidays
+                .iter()
+                .any(|holiday| holiday.source == HolidaySource::National)
+        );
+        assert!(holidays.is_sorted_by_key(|holiday| holiday.date));
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn run_データがある場合_投入しないこと(pool: DbPool) {
+        run(&pool, &HolidaysConfig::default()).await.unwrap();
+
+        let resignations = Resignation::fetch_all(&pool).await.unwrap();
+        assert_eq!(resignations.len(), 2);
+        assert!(
+            resignations
+                .iter()
+                .all(|resignation| resignation.id == 1111 || resignation.id == 2222)
+        );
+        assert!(User::fetch_all(&pool).await.unwrap().is_empty());
+    }
+}
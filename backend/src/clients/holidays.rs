@@ -1,12 +1,46 @@
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
 use reqwest::{Client, Result};
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+    time::Instant,
+};
+use tracing::instrument;
 
-use crate::utils::url::get_base_url;
+use crate::{infrastructure::metrics::observe_holiday_fetch, utils::url::get_base_url};
 
-type FetchHolidaysResponse = HashMap<String, String>;
+type FetchHolidaysResponse = HashMap<NaiveDate, String>;
 
-pub async fn fetch_holidays() -> Result<FetchHolidaysResponse> {
-    let base_url = get_base_url("https://holidays-jp.github.io");
+/// 最後に取得できた祝日。取得元が落ちていても計算を続けられるかの判断に使う
+///
+/// 取得した内容が変わったときだけ置き換える。組み立てた `HolidayMap` は、これが置き換わると作り直される。
+static CACHED_HOLIDAYS: Lazy<RwLock<Option<Arc<FetchHolidaysResponse>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+#[instrument(err)]
+pub async fn fetch_holidays(base_url: &str) -> Result<Arc<FetchHolidaysResponse>> {
+    let base_url = get_base_url(base_url);
+    let started_at = Instant::now();
+    let response = request(base_url).await;
+    observe_holiday_fetch(response.is_ok(), started_at);
+    let response = response?;
+    let mut cached_holidays = CACHED_HOLIDAYS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    let response = match cached_holidays.as_ref() {
+        Some(cached) if **cached == response => cached.clone(),
+        _ => {
+            let response = Arc::new(response);
+            *cached_holidays = Some(response.clone());
+            response
+        }
+    };
+
+    Ok(response)
+}
+
+async fn request(base_url: &str) -> Result<FetchHolidaysResponse> {
     let client = Client::new();
     let response = client
         .get(format!("{}/api/v1/date.json", base_url))
@@ -18,10 +52,17 @@ pub async fn fetch_holidays() -> Result<FetchHolidaysResponse> {
     Ok(response)
 }
 
+pub fn cached_holidays() -> Option<Arc<FetchHolidaysResponse>> {
+    CACHED_HOLIDAYS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::mocks::server::MockServer;
+    use crate::{infrastructure::config::HolidaysConfig, tests::mocks::server::MockServer};
     use serde_json::json;
 
     #[tokio::test]
@@ -37,10 +78,14 @@ mod tests {
                 .with_body(json_body.to_string())
                 .create_async()
                 .await;
-            let response = fetch_holidays().await;
+            let response = fetch_holidays(&HolidaysConfig::default().base_url).await;
 
             assert!(response.is_ok());
-            assert_eq!(response.unwrap().get("2025-01-01").unwrap(), "休み");
+            assert_eq!(
+                response.unwrap()[&NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()],
+                "休み"
+            );
+            assert!(cached_holidays().is_some());
 
             srv.reset();
         }
@@ -60,7 +105,7 @@ mod tests {
                 .create_async()
                 .await;
 
-            let response = fetch_holidays().await;
+            let response = fetch_holidays(&HolidaysConfig::default().base_url).await;
 
             assert!(response.is_err());
             srv.reset();
@@ -1 +1,3 @@
+pub mod idempotency_key;
+pub mod paid_leave;
 pub mod url;
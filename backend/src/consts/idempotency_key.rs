@@ -0,0 +1,3 @@
+/// 冪等キーを保持する時間
+pub const IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
+pub const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
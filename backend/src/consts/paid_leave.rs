@@ -0,0 +1,6 @@
+/// 残りの有給日数の上限の既定値。労働基準法の年次有給休暇は最大 20 日で、繰り越しを含めて 40 日になる
+pub const DEFAULT_MAX_REMAINING_PAID_LEAVE_DAYS: u32 = 40;
+/// 設定できる残りの有給日数の上限。特別休暇を加える場合でも 1 年分の日数まで
+pub const MAX_REMAINING_PAID_LEAVE_DAYS_LIMIT: u32 = 365;
+/// 休暇開始日を探すときに遡る日数の上限。土日と祝日を挟んでも、上限の有給日数を十分に消化できる日数にする
+pub const MAX_VACATION_SEARCH_DAYS: u32 = 366 * 3;
@@ -0,0 +1,423 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    num::ParseIntError,
+};
+
+use async_graphql::ServerError;
+use chrono::NaiveDate;
+
+use crate::{infrastructure::locale::Locale, models::resignation::ResignationStatus};
+
+/// クライアントに返すエラー。`code` を GraphQL のエラーの `extensions.code` に載せ、
+/// フロントエンドがメッセージではなくコードで分岐できるようにする
+#[derive(Debug, Clone)]
+pub enum AppError {
+    NoPaidLeave,
+    NotFound,
+    HolidaySourceUnavailable,
+    /// async-graphql がクエリの構文や引数の型、組み込みのバリデーターで弾いたエラー。
+    /// メッセージは async-graphql が作る英語のまま返し、言語に合わせない。
+    /// このプロジェクトで決めた規則は、ここに入れずに個別のエラーにしてメッセージを一覧に載せる
+    ValidationFailed(String),
+    /// REST API で、リクエストの本文を JSON として読めないか、項目の型が合わない
+    InvalidRequestBody,
+    InvalidId,
+    InvalidDateFormat {
+        value: String,
+    },
+    InvalidDateTimeFormat {
+        value: String,
+    },
+    FutureDateRequired {
+        actual: NaiveDate,
+    },
+    NonWorkingRetirementDate {
+        date: NaiveDate,
+    },
+    IdempotencyKeyTooLong {
+        max: usize,
+    },
+    PaidLeaveDaysOutOfRange {
+        max: u32,
+    },
+    WorkingDaysBeforeOutOfRange {
+        max: u32,
+    },
+    InvalidDate,
+    VacationPeriodTooLong {
+        max_days: u32,
+    },
+    InvalidDateRange {
+        from: NaiveDate,
+        to: NaiveDate,
+    },
+    UnknownCalendar {
+        code: String,
+    },
+    /// 計算する期間に祝日のデータがない年が含まれる
+    HolidaysNotCovered {
+        from: NaiveDate,
+        to: NaiveDate,
+    },
+    InvalidStatusTransition {
+        from: ResignationStatus,
+        to: ResignationStatus,
+    },
+    IdempotencyKeyConflict,
+    PersistedQueryNotFound,
+    /// strict のときに、事前に登録していないクエリが送られてきた
+    PersistedQueryNotAllowed,
+    /// `extensions.persistedQuery` の形式が不正か、ハッシュがクエリと一致しない
+    InvalidPersistedQuery,
+    Forbidden,
+    RateLimited {
+        retry_after_secs: u64,
+    },
+    /// 内部の詳細はログにだけ残し、クライアントには返さない
+    Internal,
+}
+
+/// メッセージはリクエストごとに言語を選ぶので、thiserror の `#[error]` で固定せず `message` で組み立てる
+impl Display for AppError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(Locale::current()))
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NoPaidLeave => "NO_PAID_LEAVE",
+            Self::NotFound => "NOT_FOUND",
+            Self::HolidaySourceUnavailable => "HOLIDAY_SOURCE_UNAVAILABLE",
+            Self::ValidationFailed(_)
+            | Self::InvalidRequestBody
+            | Self::InvalidId
+            | Self::InvalidDateFormat { .. }
+            | Self::InvalidDateTimeFormat { .. }
+            | Self::FutureDateRequired { .. }
+            | Self::NonWorkingRetirementDate { .. }
+            | Self::IdempotencyKeyTooLong { .. }
+            | Self::PaidLeaveDaysOutOfRange { .. }
+            | Self::WorkingDaysBeforeOutOfRange { .. }
+            | Self::InvalidPersistedQuery
+            | Self::InvalidDateRange { .. }
+            | Self::UnknownCalendar { .. } => "VALIDATION_FAILED",
+            Self::InvalidDate => "INVALID_DATE",
+            Self::VacationPeriodTooLong { .. } => "VACATION_PERIOD_TOO_LONG",
+            Self::HolidaysNotCovered { .. } => "HOLIDAYS_NOT_COVERED",
+            Self::InvalidStatusTransition { .. } => "INVALID_STATUS_TRANSITION",
+            Self::IdempotencyKeyConflict => "IDEMPOTENCY_KEY_CONFLICT",
+            Self::PersistedQueryNotFound => "PERSISTED_QUERY_NOT_FOUND",
+            Self::PersistedQueryNotAllowed => "PERSISTED_QUERY_NOT_ALLOWED",
+            Self::Forbidden => "FORBIDDEN",
+            Self::RateLimited { .. } => "RATE_LIMITED",
+            Self::Internal => "INTERNAL_SERVER_ERROR",
+        }
+    }
+
+    /// クライアントに返すメッセージ。ここが日本語と英語のメッセージの一覧になる
+    pub fn message(&self, locale: Locale) -> String {
+        match (self, locale) {
+            (Self::NoPaidLeave, Locale::Ja) => "有給がありません".to_string(),
+            (Self::NoPaidLeave, Locale::En) => "There is no paid leave left".to_string(),
+            (Self::NotFound, Locale::Ja) => "対象のデータが見つかりません".to_string(),
+            (Self::NotFound, Locale::En) => "The requested data was not found".to_string(),
+            (Self::HolidaySourceUnavailable, Locale::Ja) => "祝日を取得できません".to_string(),
+            (Self::HolidaySourceUnavailable, Locale::En) => {
+                "The holiday data is unavailable".to_string()
+            }
+            (Self::ValidationFailed(message), _) => message.clone(),
+            (Self::InvalidRequestBody, Locale::Ja) => {
+                "リクエストの本文を読めません。JSON の形式と各項目の型を確かめてください".to_string()
+            }
+            (Self::InvalidRequestBody, Locale::En) => {
+                "The request body could not be read. Check the JSON format and the type of each field"
+                    .to_string()
+            }
+            (Self::InvalidId, Locale::Ja) => "ID が不正です".to_string(),
+            (Self::InvalidId, Locale::En) => "The ID is invalid".to_string(),
+            (Self::InvalidDateFormat { value }, Locale::Ja) => {
+                format!("{value} は YYYY-MM-DD 形式の日付ではありません")
+            }
+            (Self::InvalidDateFormat { value }, Locale::En) => {
+                format!("{value} is not a date in YYYY-MM-DD format")
+            }
+            (Self::InvalidDateTimeFormat { value }, Locale::Ja) => {
+                format!("{value} は YYYY-MM-DD HH:MM:SS 形式の日時ではありません")
+            }
+            (Self::InvalidDateTimeFormat { value }, Locale::En) => {
+                format!("{value} is not a date and time in YYYY-MM-DD HH:MM:SS format")
+            }
+            (Self::FutureDateRequired { actual }, Locale::Ja) => format!(
+                "未来の日付を指定してください (指定された日付: {})",
+                locale.format_date(*actual)
+            ),
+            (Self::FutureDateRequired { actual }, Locale::En) => format!(
+                "Please set a future date (given: {})",
+                locale.format_date(*actual)
+            ),
+            (Self::NonWorkingRetirementDate { date }, Locale::Ja) => format!(
+                "退職日には営業日を指定してください (指定された日付: {})",
+                locale.format_date(*date)
+            ),
+            (Self::NonWorkingRetirementDate { date }, Locale::En) => format!(
+                "Please set a business day as the retirement date (given: {})",
+                locale.format_date(*date)
+            ),
+            (Self::IdempotencyKeyTooLong { max }, Locale::Ja) => {
+                format!("冪等キーは {max} バイト以内にしてください")
+            }
+            (Self::IdempotencyKeyTooLong { max }, Locale::En) => {
+                format!("The idempotency key must be at most {max} bytes")
+            }
+            (Self::PaidLeaveDaysOutOfRange { max }, Locale::Ja) => {
+                format!("残りの有給日数は 0 日から {max} 日までにしてください")
+            }
+            (Self::PaidLeaveDaysOutOfRange { max }, Locale::En) => {
+                format!("The remaining paid leave must be between 0 and {max} days")
+            }
+            (Self::WorkingDaysBeforeOutOfRange { max }, Locale::Ja) => {
+                format!("期限は休暇開始日の 0 営業日前から {max} 営業日前までにしてください")
+            }
+            (Self::WorkingDaysBeforeOutOfRange { max }, Locale::En) => {
+                format!(
+                    "The due date must be between 0 and {max} business days before the leave starts"
+                )
+            }
+            (Self::VacationPeriodTooLong { max_days }, Locale::Ja) => {
+                format!("休暇期間が {max_days} 日を超えるため計算できません")
+            }
+            (Self::VacationPeriodTooLong { max_days }, Locale::En) => {
+                format!("Cannot calculate a leave period longer than {max_days} days")
+            }
+            (Self::InvalidDate, Locale::Ja) => "計算できる範囲を超えた日付です".to_string(),
+            (Self::InvalidDate, Locale::En) => "The date is out of range".to_string(),
+            (Self::InvalidDateRange { from, to }, Locale::Ja) => format!(
+                "開始日 ({}) は終了日 ({}) 以前にしてください",
+                locale.format_date(*from),
+                locale.format_date(*to)
+            ),
+            (Self::InvalidDateRange { from, to }, Locale::En) => format!(
+                "The start date ({}) must not be after the end date ({})",
+                locale.format_date(*from),
+                locale.format_date(*to)
+            ),
+            (Self::HolidaysNotCovered { from, to }, Locale::Ja) => format!(
+                "{} から {} までの期間には祝日のデータがない年が含まれるため計算できません",
+                locale.format_date(*from),
+                locale.format_date(*to)
+            ),
+            (Self::HolidaysNotCovered { from, to }, Locale::En) => format!(
+                "Cannot calculate because no holiday data is available for part of the period from {} to {}",
+                locale.format_date(*from),
+                locale.format_date(*to)
+            ),
+            (Self::UnknownCalendar { code }, Locale::Ja) => {
+                format!("{code} は対応していないカレンダーです")
+            }
+            (Self::UnknownCalendar { code }, Locale::En) => {
+                format!("The calendar {code} is not supported")
+            }
+            (Self::InvalidStatusTransition { from, to }, Locale::Ja) => format!(
+                "{} から {} には変更できません",
+                status_name(*from, locale),
+                status_name(*to, locale)
+            ),
+            (Self::InvalidStatusTransition { from, to }, Locale::En) => format!(
+                "Cannot change the status from {} to {}",
+                status_name(*from, locale),
+                status_name(*to, locale)
+            ),
+            (Self::IdempotencyKeyConflict, Locale::Ja) => {
+                "同じ冪等キーが異なるリクエストで使われています".to_string()
+            }
+            (Self::IdempotencyKeyConflict, Locale::En) => {
+                "The idempotency key was already used for a different request".to_string()
+            }
+            (Self::PersistedQueryNotFound, Locale::Ja) => {
+                "登録されていないクエリです。クエリ全文を送ってください".to_string()
+            }
+            (Self::PersistedQueryNotFound, Locale::En) => {
+                "The persisted query was not found. Please send the full query".to_string()
+            }
+            (Self::PersistedQueryNotAllowed, Locale::Ja) => {
+                "事前に登録されたクエリ以外は実行できません".to_string()
+            }
+            (Self::PersistedQueryNotAllowed, Locale::En) => {
+                "Only pre-registered queries are allowed".to_string()
+            }
+            (Self::InvalidPersistedQuery, Locale::Ja) => {
+                "persistedQuery の形式が不正か、ハッシュがクエリと一致しません".to_string()
+            }
+            (Self::InvalidPersistedQuery, Locale::En) => {
+                "The persistedQuery is malformed or its hash does not match the query".to_string()
+            }
+            (Self::Forbidden, Locale::Ja) => "管理者のみ実行できます".to_string(),
+            (Self::Forbidden, Locale::En) => "Only administrators can do this".to_string(),
+            (Self::RateLimited { retry_after_secs }, Locale::Ja) => {
+                format!("リクエストが多すぎます。{retry_after_secs} 秒後にもう一度お試しください")
+            }
+            (Self::RateLimited { retry_after_secs }, Locale::En) => {
+                format!("Too many requests. Please try again in {retry_after_secs} seconds")
+            }
+            (Self::Internal, Locale::Ja) => "サーバーでエラーが発生しました".to_string(),
+            (Self::Internal, Locale::En) => "An internal server error occurred".to_string(),
+        }
+    }
+
+    /// リゾルバーが返したエラーを、原因となった型から分類する
+    pub fn from_server_error(error: &ServerError) -> Self {
+        if let Some(error) = error.source::<AppError>() {
+            return error.clone();
+        }
+        if let Some(error) = error.source::<anyhow::Error>() {
+            return Self::from_anyhow(error);
+        }
+        if let Some(error) = error.source::<sqlx::Error>() {
+            return Self::from_sqlx(error);
+        }
+        if error.source::<reqwest::Error>().is_some() {
+            return Self::HolidaySourceUnavailable;
+        }
+        if error.source::<ParseIntError>().is_some() {
+            return Self::InvalidId;
+        }
+        // 原因がないのは、クエリの構文や引数の検証で async-graphql が弾いたエラー
+        if error.source.is_none() {
+            return Self::ValidationFailed(error.message.clone());
+        }
+
+        Self::Internal
+    }
+
+    pub fn from_anyhow(error: &anyhow::Error) -> Self {
+        if let Some(error) = error.downcast_ref::<AppError>() {
+            return error.clone();
+        }
+        if let Some(error) = error.downcast_ref::<sqlx::Error>() {
+            return Self::from_sqlx(error);
+        }
+        if error.downcast_ref::<reqwest::Error>().is_some() {
+            return Self::HolidaySourceUnavailable;
+        }
+
+        Self::Internal
+    }
+
+    fn from_sqlx(error: &sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => Self::NotFound,
+            _ => Self::Internal,
+        }
+    }
+}
+
+fn status_name(status: ResignationStatus, locale: Locale) -> &'static str {
+    match (status, locale) {
+        (ResignationStatus::Draft, Locale::Ja) => "下書き",
+        (ResignationStatus::Submitted, Locale::Ja) => "提出済み",
+        (ResignationStatus::Approved, Locale::Ja) => "承認済み",
+        (ResignationStatus::Rejected, Locale::Ja) => "差し戻し",
+        (ResignationStatus::Withdrawn, Locale::Ja) => "取り下げ",
+        (ResignationStatus::Completed, Locale::Ja) => "完了",
+        (ResignationStatus::Draft, Locale::En) => "draft",
+        (ResignationStatus::Submitted, Locale::En) => "submitted",
+        (ResignationStatus::Approved, Locale::En) => "approved",
+        (ResignationStatus::Rejected, Locale::En) => "rejected",
+        (ResignationStatus::Withdrawn, Locale::En) => "withdrawn",
+        (ResignationStatus::Completed, Locale::En) => "completed",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_error(error: impl Into<async_graphql::Error>) -> ServerError {
+        error.into().into_server_error(Default::default())
+    }
+
+    #[test]
+    fn from_server_error_ドメインのエラーの場合_そのまま分類すること() {
+        let error = server_error(AppError::NoPaidLeave);
+
+        assert_eq!(AppError::from_server_error(&error).code(), "NO_PAID_LEAVE");
+    }
+
+    #[test]
+    fn from_server_error_anyhowで包まれている場合_中身で分類すること() {
+        let error = server_error(anyhow::Error::from(AppError::IdempotencyKeyConflict));
+
+        assert_eq!(
+            AppError::from_server_error(&error).code(),
+            "IDEMPOTENCY_KEY_CONFLICT"
+        );
+    }
+
+    #[test]
+    fn from_server_error_行がない場合_not_foundになること() {
+        let error = server_error(sqlx::Error::RowNotFound);
+
+        assert_eq!(AppError::from_server_error(&error).code(), "NOT_FOUND");
+    }
+
+    #[test]
+    fn from_server_error_想定外のエラーの場合_内部エラーになること() {
+        let error = server_error(anyhow::anyhow!("connection refused"));
+
+        let result = AppError::from_server_error(&error);
+
+        assert_eq!(result.code(), "INTERNAL_SERVER_ERROR");
+        assert_eq!(result.to_string(), "サーバーでエラーが発生しました");
+    }
+
+    #[test]
+    fn message_言語ごとのメッセージを返すこと() {
+        let error = AppError::InvalidStatusTransition {
+            from: ResignationStatus::Draft,
+            to: ResignationStatus::Completed,
+        };
+
+        assert_eq!(
+            error.message(Locale::Ja),
+            "下書き から 完了 には変更できません"
+        );
+        assert_eq!(
+            error.message(Locale::En),
+            "Cannot change the status from draft to completed"
+        );
+    }
+
+    #[test]
+    fn message_async_graphqlの検証エラーの場合_英語のまま返すこと() {
+        let error = AppError::from_server_error(&ServerError::new(
+            r#"Unknown field "unknown" on type "Query"."#,
+            None,
+        ));
+
+        assert_eq!(error.code(), "VALIDATION_FAILED");
+        assert_eq!(
+            error.message(Locale::Ja),
+            r#"Unknown field "unknown" on type "Query"."#
+        );
+    }
+
+    #[test]
+    fn message_日付を言語に合わせて表記すること() {
+        let error = AppError::FutureDateRequired {
+            actual: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        };
+
+        assert_eq!(
+            error.message(Locale::Ja),
+            "未来の日付を指定してください (指定された日付: 2025年1月1日)"
+        );
+        assert_eq!(
+            error.message(Locale::En),
+            "Please set a future date (given: Jan 1, 2025)"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+INSERT INTO
+  handover_task (
+    id,
+    resignation_id,
+    title,
+    working_days_before,
+    completed,
+    created_at
+  )
+VALUES
+  (1, 2222, '引き継ぎ資料の作成', 5, TRUE, '2025-02-01 00:00:00'),
+  (2, 2222, '取引先への挨拶', 1, FALSE, '2025-02-01 00:00:00');
@@ -1,5 +1,11 @@
+pub mod enums;
+pub mod extensions;
+pub mod guards;
 pub mod mutations;
 pub mod objects;
 pub mod queries;
 pub mod scalars;
 pub mod validations;
+
+/// 祝日を外部から取得するフィールドの複雑度。別名で並べて取得元に負荷を掛けられないよう重く数える
+pub const HOLIDAY_FETCH_COMPLEXITY: usize = 20;
@@ -0,0 +1,3 @@
+pub mod holiday_source;
+pub mod resignation_status;
+pub mod retirement_date_policy;
@@ -0,0 +1,18 @@
+use async_graphql::Enum;
+
+use crate::models::holiday::HolidaySource as HolidaySourceModel;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum HolidaySource {
+    National,
+    Company,
+}
+
+impl From<HolidaySourceModel> for HolidaySource {
+    fn from(source: HolidaySourceModel) -> Self {
+        match source {
+            HolidaySourceModel::National => Self::National,
+            HolidaySourceModel::Company => Self::Company,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use async_graphql::Enum;
+
+use crate::models::resignation::ResignationStatus as ResignationStatusModel;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ResignationStatus {
+    Draft,
+    Submitted,
+    Approved,
+    Rejected,
+    Withdrawn,
+    Completed,
+}
+
+impl From<ResignationStatusModel> for ResignationStatus {
+    fn from(status: ResignationStatusModel) -> Self {
+        match status {
+            ResignationStatusModel::Draft => Self::Draft,
+            ResignationStatusModel::Submitted => Self::Submitted,
+            ResignationStatusModel::Approved => Self::Approved,
+            ResignationStatusModel::Rejected => Self::Rejected,
+            ResignationStatusModel::Withdrawn => Self::Withdrawn,
+            ResignationStatusModel::Completed => Self::Completed,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use async_graphql::Enum;
+
+use crate::infrastructure::config::RetirementDatePolicy as RetirementDatePolicyConfig;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RetirementDatePolicy {
+    Allow,
+    Reject,
+    PreviousBusinessDay,
+    NextBusinessDay,
+}
+
+impl From<RetirementDatePolicyConfig> for RetirementDatePolicy {
+    fn from(policy: RetirementDatePolicyConfig) -> Self {
+        match policy {
+            RetirementDatePolicyConfig::Allow => Self::Allow,
+            RetirementDatePolicyConfig::Reject => Self::Reject,
+            RetirementDatePolicyConfig::PreviousBusinessDay => Self::PreviousBusinessDay,
+            RetirementDatePolicyConfig::NextBusinessDay => Self::NextBusinessDay,
+        }
+    }
+}
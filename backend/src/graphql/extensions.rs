@@ -0,0 +1,4 @@
+pub mod audit_log;
+pub mod error_code;
+pub mod metrics;
+pub mod persisted_queries;
@@ -0,0 +1,342 @@
+use std::{
+    any::TypeId,
+    sync::{Arc, Mutex},
+};
+
+use async_graphql::{
+    Request, Response, ServerResult, Variables,
+    async_trait::async_trait,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest, NextRequest},
+    parser::{
+        parse_query,
+        types::{OperationType, Selection},
+    },
+};
+use serde_json::Value;
+
+use crate::{
+    infrastructure::actor::Actor,
+    infrastructure::database::DbPool,
+    models::audit_log::{AuditLog as AuditLogModel, AuditLogInput, redact},
+};
+
+/// ミューテーションの実行内容を audit_log テーブルに記録する
+///
+/// 実行した結果だけでなく、構文エラーや検証エラー、ガード、永続化クエリで断ったものも記録するため、
+/// リクエスト全体を包む `request` で記録する。
+pub struct AuditLog;
+
+impl ExtensionFactory for AuditLog {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(AuditLogExtension::default())
+    }
+}
+
+/// 記録するリクエストの内容
+struct Operation {
+    query: String,
+    operation_name: Option<String>,
+    variables: Variables,
+    actor: Actor,
+}
+
+#[derive(Default)]
+struct AuditLogExtension {
+    operation: Mutex<Option<Operation>>,
+}
+
+#[async_trait]
+impl Extension for AuditLogExtension {
+    async fn request(&self, ctx: &ExtensionContext<'_>, next: NextRequest<'_>) -> Response {
+        let response = next.run(ctx).await;
+        let Some(operation) = self.operation.lock().unwrap().take() else {
+            return response;
+        };
+        if let Ok(pool) = ctx.data::<DbPool>() {
+            let errors = response
+                .errors
+                .iter()
+                .map(|error| error.message.clone())
+                .collect::<Vec<_>>();
+            record(
+                pool,
+                &operation.query,
+                operation.operation_name.as_deref(),
+                &operation.variables,
+                operation.actor,
+                &errors,
+            )
+            .await;
+        }
+
+        response
+    }
+
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        // 永続化クエリで断った場合もクエリ全文が分かれば記録できるよう、解決する前にも控えておく
+        self.keep(&request);
+        let request = next.run(ctx, request).await?;
+        self.keep(&request);
+
+        Ok(request)
+    }
+}
+
+impl AuditLogExtension {
+    fn keep(&self, request: &Request) {
+        let actor = request
+            .data
+            .get(&TypeId::of::<Actor>())
+            .and_then(|actor| actor.downcast_ref::<Actor>())
+            .cloned()
+            .unwrap_or_default();
+        *self.operation.lock().unwrap() = Some(Operation {
+            query: request.query.clone(),
+            operation_name: request.operation_name.clone(),
+            variables: request.variables.clone(),
+            actor,
+        });
+    }
+}
+
+/// 実行するミューテーションの名前。操作に名前がなければ、ルートのフィールド名をつなげる。
+/// ミューテーションでなければ `None` を返す
+///
+/// 構文エラーで読めないクエリは、`mutation` で始まっていればミューテーションとみなす。
+fn mutation_name(query: &str, operation_name: Option<&str>) -> Option<String> {
+    // ほとんどのリクエストはクエリなので、読まずに済ませる
+    if !query.contains("mutation") {
+        return None;
+    }
+    let Ok(document) = parse_query(query) else {
+        return query
+            .trim_start()
+            .starts_with("mutation")
+            .then(|| operation_name.unwrap_or("mutation").to_string());
+    };
+    let mut operations = document.operations.iter();
+    let (name, operation) = match operation_name {
+        Some(operation_name) => {
+            operations.find(|(name, _)| name.map(|name| name.as_str()) == Some(operation_name))?
+        }
+        None => {
+            let operation = operations.next()?;
+            if operations.next().is_some() {
+                return None;
+            }
+            operation
+        }
+    };
+    if operation.node.ty != OperationType::Mutation {
+        return None;
+    }
+
+    Some(name.map(|name| name.to_string()).unwrap_or_else(|| {
+        operation
+            .node
+            .selection_set
+            .node
+            .items
+            .iter()
+            .filter_map(|selection| match &selection.node {
+                Selection::Field(field) => Some(field.node.name.node.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }))
+}
+
+/// ミューテーションであれば、送られてきた内容と結果を記録する
+async fn record(
+    pool: &DbPool,
+    query: &str,
+    operation_name: Option<&str>,
+    variables: &Variables,
+    actor: Actor,
+    errors: &[String],
+) {
+    let Some(operation_name) = mutation_name(query, operation_name) else {
+        return;
+    };
+    let variables = variables
+        .clone()
+        .into_value()
+        .into_json()
+        .map(redact)
+        .unwrap_or(Value::Null);
+    let input = AuditLogInput {
+        operation_name,
+        variables: variables.to_string(),
+        claimed_actor: actor.claimed_name,
+        client_ip: actor.client_ip,
+        succeeded: errors.is_empty(),
+        errors: (!errors.is_empty()).then(|| serde_json::to_string(errors).unwrap_or_default()),
+    };
+    AuditLogModel::record(pool, &input).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use anyhow::{Ok, Result};
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use graphql_parser::parse_query;
+    use http_body_util::BodyExt;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{models::audit_log::AuditLogFilter, tests::utils::client::client};
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn mutation_監査ログを記録すること(pool: DbPool) -> Result<()> {
+        let (addr, client) = client(pool.clone()).await;
+        let query =
+            parse_query::<String>(&fs::read_to_string("graphql/mutations/resignation.gql")?)?
+                .to_string();
+        let variables = json!({
+            "input": {
+                "retirementDate": "9999-01-01",
+                "remainingPaidLeaveDays": 10
+            }
+        });
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .header("X-Actor", "yamada")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        response.into_body().collect().await?;
+        let filter = AuditLogFilter {
+            limit: 100,
+            ..Default::default()
+        };
+        let audit_logs = AuditLogModel::fetch_all(&pool, &filter).await?;
+        assert_eq!(audit_logs.len(), 1);
+        assert_eq!(audit_logs[0].operation_name, "post");
+        assert_eq!(audit_logs[0].claimed_actor.as_deref(), Some("yamada"));
+        assert!(audit_logs[0].client_ip.is_some());
+        assert!(audit_logs[0].succeeded);
+        assert_eq!(
+            serde_json::from_str::<Value>(&audit_logs[0].variables)?,
+            variables
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../../fixtures/resignation/resignations.sql")
+    )]
+    async fn query_監査ログを記録しないこと(pool: DbPool) -> Result<()> {
+        let (addr, client) = client(pool.clone()).await;
+        let query = parse_query::<String>(&fs::read_to_string(
+            "graphql/queries/latest_resignation.gql",
+        )?)?
+        .to_string();
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(json!({ "query": query }).to_string()))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        response.into_body().collect().await?;
+        let filter = AuditLogFilter {
+            limit: 100,
+            ..Default::default()
+        };
+        let audit_logs = AuditLogModel::fetch_all(&pool, &filter).await?;
+        assert_eq!(audit_logs.len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mutation_name_ミューテーションの名前を返すこと() {
+        let query = "mutation post { postResignation { id } } query get { resignations { id } }";
+
+        assert_eq!(mutation_name(query, Some("post")).as_deref(), Some("post"));
+        assert_eq!(mutation_name(query, Some("get")), None);
+        assert_eq!(mutation_name(query, None), None);
+        assert_eq!(
+            mutation_name("mutation { a { id } b { id } }", None).as_deref(),
+            Some("a,b")
+        );
+        assert_eq!(mutation_name("{ resignations { id } }", None), None);
+    }
+
+    #[test]
+    fn mutation_name_構文エラーの場合_mutationで始まればミューテーションとみなすこと() {
+        assert_eq!(
+            mutation_name("mutation post { postResignation(", Some("post")).as_deref(),
+            Some("post")
+        );
+        assert_eq!(mutation_name("{ mutation(", None), None);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn mutation_検証エラーで断った場合も監査ログを記録すること(
+        pool: DbPool,
+    ) -> Result<()> {
+        let (addr, client) = client(pool.clone()).await;
+        let query = "mutation post { postResignation(input: {}) { unknown } }";
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .header("X-Actor", "yamada")
+                    .body(Body::from(json!({ "query": query }).to_string()))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        response.into_body().collect().await?;
+        let filter = AuditLogFilter {
+            limit: 100,
+            ..Default::default()
+        };
+        let audit_logs = AuditLogModel::fetch_all(&pool, &filter).await?;
+        assert_eq!(audit_logs.len(), 1);
+        assert_eq!(audit_logs[0].operation_name, "post");
+        assert_eq!(audit_logs[0].claimed_actor.as_deref(), Some("yamada"));
+        assert!(!audit_logs[0].succeeded);
+        assert!(audit_logs[0].errors.is_some());
+
+        Ok(())
+    }
+}
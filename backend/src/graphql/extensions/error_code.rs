@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use async_graphql::{
+    Response,
+    async_trait::async_trait,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextRequest},
+};
+
+use crate::errors::AppError;
+
+/// エラーを分類して `extensions.code` を付け、内部の詳細を伏せたメッセージに置き換える
+pub struct ErrorCode;
+
+impl ExtensionFactory for ErrorCode {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ErrorCodeExtension)
+    }
+}
+
+struct ErrorCodeExtension;
+
+#[async_trait]
+impl Extension for ErrorCodeExtension {
+    async fn request(&self, ctx: &ExtensionContext<'_>, next: NextRequest<'_>) -> Response {
+        let mut response = next.run(ctx).await;
+        for error in &mut response.errors {
+            let app_error = AppError::from_server_error(error);
+            // 伏せる前のメッセージをログに残す
+            match app_error {
+                AppError::Internal => tracing::error!(
+                    code = app_error.code(),
+                    message = %error.message,
+                    path = ?error.path,
+                    "GraphQL のリクエストでエラーが発生しました"
+                ),
+                _ => tracing::warn!(
+                    code = app_error.code(),
+                    message = %error.message,
+                    path = ?error.path,
+                    "GraphQL のリクエストでエラーが発生しました"
+                ),
+            }
+            error.message = app_error.to_string();
+            error
+                .extensions
+                .get_or_insert_with(Default::default)
+                .set("code", app_error.code());
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{infrastructure::database::DbPool, tests::utils::client::client};
+
+    async fn execute(pool: DbPool, query: &str) -> Value {
+        execute_in(pool, query, "ja").await
+    }
+
+    async fn execute_in(pool: DbPool, query: &str, accept_language: &str) -> Value {
+        let (addr, client) = client(pool).await;
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .header("Accept-Language", accept_language)
+                    .body(Body::from(json!({ "query": query }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn データがない場合_not_foundを返すこと(pool: DbPool) {
+        let body = execute(pool, "{ latestResignation { id } }").await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], json!("NOT_FOUND"));
+        assert_eq!(
+            body["errors"][0]["message"],
+            json!("対象のデータが見つかりません")
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn 英語を希望している場合_英語のメッセージを返すこと(
+        pool: DbPool,
+    ) {
+        let body = execute_in(pool, "{ latestResignation { id } }", "en-US,ja;q=0.5").await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], json!("NOT_FOUND"));
+        assert_eq!(
+            body["errors"][0]["message"],
+            json!("The requested data was not found")
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn クエリが不正な場合_validation_failedを返すこと(pool: DbPool) {
+        let body = execute(pool, "{ unknownField }").await;
+
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("VALIDATION_FAILED")
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn 管理者でない場合_forbiddenを返すこと(pool: DbPool) {
+        let body = execute(pool, "{ auditLogs { id } }").await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], json!("FORBIDDEN"));
+    }
+}
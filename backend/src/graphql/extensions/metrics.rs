@@ -0,0 +1,206 @@
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use async_graphql::{
+    Response, ServerResult, Variables,
+    async_trait::async_trait,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute, NextParseQuery},
+    parser::types::{ExecutableDocument, OperationType, Selection},
+};
+
+use crate::infrastructure::metrics::GRAPHQL_OPERATION_DURATION_SECONDS;
+
+/// オペレーションごとの実行時間を Prometheus のメトリクスに記録する
+pub struct Metrics;
+
+impl ExtensionFactory for Metrics {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(MetricsExtension::default())
+    }
+}
+
+struct Operation {
+    name: Option<String>,
+    ty: OperationType,
+    root_fields: BTreeSet<String>,
+}
+
+#[derive(Default)]
+struct MetricsExtension {
+    operations: Mutex<Vec<Operation>>,
+}
+
+#[async_trait]
+impl Extension for MetricsExtension {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+        let operations = document
+            .operations
+            .iter()
+            .map(|(name, operation)| Operation {
+                name: name.map(|name| name.to_string()),
+                ty: operation.node.ty,
+                root_fields: operation
+                    .node
+                    .selection_set
+                    .node
+                    .items
+                    .iter()
+                    .filter_map(|selection| match &selection.node {
+                        Selection::Field(field) => Some(field.node.name.node.to_string()),
+                        _ => None,
+                    })
+                    .collect(),
+            })
+            .collect();
+        *self.operations.lock().unwrap() = operations;
+
+        Ok(document)
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let started_at = Instant::now();
+        let response = next.run(ctx, operation_name).await;
+        let (operation_type, operation_name) = self.labels(operation_name);
+        let succeeded = if response.errors.is_empty() {
+            "true"
+        } else {
+            "false"
+        };
+        GRAPHQL_OPERATION_DURATION_SECONDS
+            .with_label_values(&[operation_type, &operation_name, succeeded])
+            .observe(started_at.elapsed().as_secs_f64());
+
+        response
+    }
+}
+
+impl MetricsExtension {
+    /// ラベルにはスキーマにあるルートフィールドの名前だけを使う。
+    /// オペレーション名はクライアントが自由に付けられるので、そのまま使うと時系列がいくらでも増える。
+    /// `execute` の時点では検証を通っているので、ルートフィールドはスキーマにあるものに限られる。
+    /// 複数のフィールドを並べた場合は組み合わせの数だけ増えるので、まとめて `other` にする
+    fn labels(&self, operation_name: Option<&str>) -> (&'static str, String) {
+        let operations = self.operations.lock().unwrap();
+        let operation = match operation_name {
+            Some(operation_name) => operations
+                .iter()
+                .find(|operation| operation.name.as_deref() == Some(operation_name)),
+            None if operations.len() == 1 => operations.first(),
+            None => None,
+        };
+        let Some(operation) = operation else {
+            return ("unknown", "unknown".to_string());
+        };
+        let operation_type = match operation.ty {
+            OperationType::Query => "query",
+            OperationType::Mutation => "mutation",
+            OperationType::Subscription => "subscription",
+        };
+        let mut root_fields = operation.root_fields.iter();
+        let operation_name = match (root_fields.next(), root_fields.next()) {
+            (Some(root_field), None) => root_field.clone(),
+            _ => "other".to_string(),
+        };
+
+        (operation_type, operation_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extension(operations: Vec<(Option<&str>, OperationType, Vec<&str>)>) -> MetricsExtension {
+        MetricsExtension {
+            operations: Mutex::new(
+                operations
+                    .into_iter()
+                    .map(|(name, ty, root_fields)| Operation {
+                        name: name.map(str::to_string),
+                        ty,
+                        root_fields: root_fields.into_iter().map(str::to_string).collect(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn labels_オペレーション名を付けた場合_ルートフィールドの名前を返すこと() {
+        let extension = extension(vec![(
+            Some("RandomName12345"),
+            OperationType::Query,
+            vec!["latestResignation"],
+        )]);
+
+        assert_eq!(
+            extension.labels(Some("RandomName12345")),
+            ("query", "latestResignation".to_string())
+        );
+    }
+
+    #[test]
+    fn labels_同じフィールドを別名で並べた場合_ルートフィールドの名前を返すこと() {
+        let extension = extension(vec![(
+            None,
+            OperationType::Mutation,
+            vec!["postResignation", "postResignation"],
+        )]);
+
+        assert_eq!(
+            extension.labels(None),
+            ("mutation", "postResignation".to_string())
+        );
+    }
+
+    #[test]
+    fn labels_複数のフィールドを並べた場合_otherを返すこと() {
+        let extension = extension(vec![(
+            None,
+            OperationType::Query,
+            vec!["holidays", "latestResignation"],
+        )]);
+
+        assert_eq!(extension.labels(None), ("query", "other".to_string()));
+    }
+
+    #[test]
+    fn labels_フラグメントだけの場合_otherを返すこと() {
+        let extension = extension(vec![(None, OperationType::Query, vec![])]);
+
+        assert_eq!(extension.labels(None), ("query", "other".to_string()));
+    }
+
+    #[test]
+    fn labels_オペレーションが見つからない場合_unknownを返すこと() {
+        let extension = extension(vec![
+            (Some("First"), OperationType::Query, vec!["holidays"]),
+            (
+                Some("Second"),
+                OperationType::Query,
+                vec!["latestResignation"],
+            ),
+        ]);
+
+        assert_eq!(
+            extension.labels(Some("Third")),
+            ("unknown", "unknown".to_string())
+        );
+        assert_eq!(extension.labels(None), ("unknown", "unknown".to_string()));
+    }
+}
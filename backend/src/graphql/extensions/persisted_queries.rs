@@ -0,0 +1,451 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+
+use anyhow::Context;
+use async_graphql::{
+    Pos, Request, ServerResult,
+    async_trait::async_trait,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest},
+};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    errors::AppError,
+    infrastructure::{
+        config::{PersistedQueriesConfig, PersistedQueryStoreKind},
+        database::DbPool,
+    },
+    models::persisted_query::PersistedQuery as PersistedQueryModel,
+};
+
+/// APQ で登録されたクエリの保存先。
+/// 上限を超える分は登録しない。クライアントはクエリ全文を送り続ければ動く
+#[async_trait]
+pub trait PersistedQueryStore: Send + Sync {
+    async fn get(&self, sha256_hash: &str) -> anyhow::Result<Option<String>>;
+    async fn set(&self, sha256_hash: &str, query: &str) -> anyhow::Result<()>;
+}
+
+/// プロセス内に保持する。再起動すると消え、サーバーが複数台あると共有されない
+pub struct InMemoryStore {
+    max_entries: usize,
+    max_query_length: usize,
+    queries: RwLock<HashMap<String, String>>,
+}
+
+impl InMemoryStore {
+    pub fn new(max_entries: usize, max_query_length: usize) -> Self {
+        Self {
+            max_entries,
+            max_query_length,
+            queries: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl PersistedQueryStore for InMemoryStore {
+    async fn get(&self, sha256_hash: &str) -> anyhow::Result<Option<String>> {
+        let queries = self
+            .queries
+            .read()
+            .unwrap_or_else(|error| error.into_inner());
+
+        Ok(queries.get(sha256_hash).cloned())
+    }
+
+    async fn set(&self, sha256_hash: &str, query: &str) -> anyhow::Result<()> {
+        if query.len() > self.max_query_length {
+            return Ok(());
+        }
+        let mut queries = self
+            .queries
+            .write()
+            .unwrap_or_else(|error| error.into_inner());
+        if queries.len() < self.max_entries {
+            queries.insert(sha256_hash.to_string(), query.to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// persisted_query テーブルに保存する。再起動後も、複数台のサーバーの間でも使える
+pub struct DatabaseStore {
+    pool: DbPool,
+    max_entries: usize,
+    max_query_length: usize,
+}
+
+impl DatabaseStore {
+    pub fn new(pool: DbPool, max_entries: usize, max_query_length: usize) -> Self {
+        Self {
+            pool,
+            max_entries,
+            max_query_length,
+        }
+    }
+}
+
+#[async_trait]
+impl PersistedQueryStore for DatabaseStore {
+    async fn get(&self, sha256_hash: &str) -> anyhow::Result<Option<String>> {
+        let persisted_query = PersistedQueryModel::fetch(&self.pool, sha256_hash).await?;
+
+        Ok(persisted_query.map(|persisted_query| persisted_query.query))
+    }
+
+    async fn set(&self, sha256_hash: &str, query: &str) -> anyhow::Result<()> {
+        if query.len() > self.max_query_length {
+            return Ok(());
+        }
+        // 数えてから登録するまでに他のリクエストが登録すると、上限をわずかに超えることがある
+        let count = PersistedQueryModel::count(&self.pool).await?;
+        if usize::try_from(count).is_ok_and(|count| count >= self.max_entries) {
+            return Ok(());
+        }
+        PersistedQueryModel::insert(&self.pool, sha256_hash, query).await?;
+
+        Ok(())
+    }
+}
+
+/// `extensions.persistedQuery` で送られてくる値
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedQueryExtension {
+    sha256_hash: String,
+}
+
+pub fn sha256_hash(query: &str) -> String {
+    format!("{:x}", Sha256::digest(query.as_bytes()))
+}
+
+/// ディレクトリ以下の .gql ファイルを読み込み、ハッシュとクエリの組にする
+pub fn load_operations(dir: &Path) -> io::Result<HashMap<String, String>> {
+    let mut operations = HashMap::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            operations.extend(load_operations(&path)?);
+        } else if path.extension().is_some_and(|extension| extension == "gql") {
+            let query = fs::read_to_string(&path)?;
+            operations.insert(sha256_hash(&query), query);
+        }
+    }
+
+    Ok(operations)
+}
+
+/// 自動永続化クエリ (APQ)。クエリ全文の代わりにハッシュだけを受け付ける
+///
+/// strict の場合は、事前に登録したクエリ (`operations_dir` の .gql ファイル) 以外を断り、
+/// 保存先への登録も参照もしない。
+///
+/// async-graphql の `ApolloPersistedQueries` は strict のように登録済みのクエリ以外を断れず、
+/// エラーも `AppError` のコードと言語に合わせられないため使わない。
+pub struct PersistedQueries {
+    store: Arc<dyn PersistedQueryStore>,
+    operations: Arc<HashMap<String, String>>,
+    strict: bool,
+}
+
+impl PersistedQueries {
+    pub fn new(
+        store: Arc<dyn PersistedQueryStore>,
+        operations: HashMap<String, String>,
+        strict: bool,
+    ) -> Self {
+        Self {
+            store,
+            operations: Arc::new(operations),
+            strict,
+        }
+    }
+
+    /// strict の場合は、登録するクエリを読み込めなければどのリクエストも断ることになるので、エラーにする
+    pub fn from_config(config: &PersistedQueriesConfig, pool: DbPool) -> anyhow::Result<Self> {
+        let store: Arc<dyn PersistedQueryStore> = match config.store {
+            PersistedQueryStoreKind::Memory => Arc::new(InMemoryStore::new(
+                config.max_entries,
+                config.max_query_length,
+            )),
+            PersistedQueryStoreKind::Database => Arc::new(DatabaseStore::new(
+                pool,
+                config.max_entries,
+                config.max_query_length,
+            )),
+        };
+        let operations = match &config.operations_dir {
+            Some(dir) => match load_operations(dir) {
+                Ok(operations) => operations,
+                Err(error) if config.strict => {
+                    return Err(error).with_context(|| {
+                        format!("登録するクエリを {} から読み込めません", dir.display())
+                    });
+                }
+                Err(error) => {
+                    tracing::error!(
+                        %error,
+                        dir = %dir.display(),
+                        "登録するクエリを読み込めませんでした"
+                    );
+                    HashMap::new()
+                }
+            },
+            None => HashMap::new(),
+        };
+
+        Ok(Self::new(store, operations, config.strict))
+    }
+}
+
+impl ExtensionFactory for PersistedQueries {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(PersistedQueriesExtension {
+            store: self.store.clone(),
+            operations: self.operations.clone(),
+            strict: self.strict,
+        })
+    }
+}
+
+struct PersistedQueriesExtension {
+    store: Arc<dyn PersistedQueryStore>,
+    operations: Arc<HashMap<String, String>>,
+    strict: bool,
+}
+
+impl PersistedQueriesExtension {
+    async fn lookup(&self, sha256_hash: &str) -> anyhow::Result<Option<String>> {
+        if let Some(query) = self.operations.get(sha256_hash) {
+            return Ok(Some(query.clone()));
+        }
+        if self.strict {
+            return Ok(None);
+        }
+
+        self.store.get(sha256_hash).await
+    }
+
+    async fn resolve(&self, mut request: Request) -> async_graphql::Result<Request> {
+        let persisted_query = request
+            .extensions
+            .get("persistedQuery")
+            .cloned()
+            .map(async_graphql::from_value::<PersistedQueryExtension>)
+            .transpose()
+            .map_err(|_| AppError::InvalidPersistedQuery)?;
+
+        match persisted_query {
+            // ハッシュだけが送られてきた
+            Some(PersistedQueryExtension { sha256_hash: hash }) if request.query.is_empty() => {
+                request.query = self
+                    .lookup(&hash)
+                    .await?
+                    .ok_or(AppError::PersistedQueryNotFound)?;
+            }
+            // 初回はハッシュとクエリ全文の両方が送られてくるので登録する
+            Some(PersistedQueryExtension { sha256_hash: hash }) => {
+                if hash != sha256_hash(&request.query) {
+                    return Err(AppError::InvalidPersistedQuery.into());
+                }
+                if self.strict {
+                    if !self.operations.contains_key(&hash) {
+                        return Err(AppError::PersistedQueryNotAllowed.into());
+                    }
+                } else {
+                    self.store.set(&hash, &request.query).await?;
+                }
+            }
+            None if self.strict => {
+                if !self.operations.contains_key(&sha256_hash(&request.query)) {
+                    return Err(AppError::PersistedQueryNotAllowed.into());
+                }
+            }
+            None => {}
+        }
+
+        Ok(request)
+    }
+}
+
+#[async_trait]
+impl Extension for PersistedQueriesExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let request = self
+            .resolve(request)
+            .await
+            .map_err(|error| error.into_server_error(Pos::default()))?;
+
+        next.run(ctx, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, Value};
+    use serde_json::json;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            100
+        }
+    }
+
+    const QUERY: &str = "{ value }";
+
+    fn schema(
+        operations: HashMap<String, String>,
+        strict: bool,
+    ) -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(PersistedQueries::new(
+                Arc::new(InMemoryStore::new(10, 1000)),
+                operations,
+                strict,
+            ))
+            .finish()
+    }
+
+    fn request(query: &str, hash: &str) -> Request {
+        let mut request = Request::new(query);
+        request.extensions.insert(
+            "persistedQuery".to_string(),
+            Value::from_json(json!({ "version": 1, "sha256Hash": hash })).unwrap(),
+        );
+
+        request
+    }
+
+    fn error_code(response: &async_graphql::Response) -> &'static str {
+        AppError::from_server_error(&response.errors[0]).code()
+    }
+
+    #[tokio::test]
+    async fn ハッシュだけの場合_登録済みのクエリを実行すること() {
+        let schema = schema(HashMap::new(), false);
+        let hash = sha256_hash(QUERY);
+
+        let response = schema.execute(request("", &hash)).await;
+        assert_eq!(error_code(&response), "PERSISTED_QUERY_NOT_FOUND");
+
+        let response = schema.execute(request(QUERY, &hash)).await;
+        assert!(response.errors.is_empty());
+
+        let response = schema.execute(request("", &hash)).await;
+        assert!(response.errors.is_empty());
+        assert_eq!(
+            response.data,
+            Value::from_json(json!({ "value": 100 })).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn ハッシュがクエリと一致しない場合_エラーになること() {
+        let schema = schema(HashMap::new(), false);
+
+        let response = schema
+            .execute(request(QUERY, &sha256_hash("{ other }")))
+            .await;
+
+        assert_eq!(error_code(&response), "VALIDATION_FAILED");
+    }
+
+    #[tokio::test]
+    async fn strictの場合_事前に登録したクエリだけを受け付けること() {
+        let operations = HashMap::from([(sha256_hash(QUERY), QUERY.to_string())]);
+        let schema = schema(operations, true);
+
+        assert!(schema.execute(QUERY).await.errors.is_empty());
+        assert!(
+            schema
+                .execute(request("", &sha256_hash(QUERY)))
+                .await
+                .errors
+                .is_empty()
+        );
+
+        let response = schema.execute("{ __typename }").await;
+        assert_eq!(error_code(&response), "PERSISTED_QUERY_NOT_ALLOWED");
+        let query = "{ __typename value }";
+        let response = schema.execute(request(query, &sha256_hash(query))).await;
+        assert_eq!(error_code(&response), "PERSISTED_QUERY_NOT_ALLOWED");
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn database_store_上限を超える場合_登録しないこと(pool: DbPool) {
+        let store = DatabaseStore::new(pool.clone(), 1, 10);
+
+        store.set("long", "{ __typename }").await.unwrap();
+        store.set("a", "{ a }").await.unwrap();
+        store.set("b", "{ b }").await.unwrap();
+
+        assert_eq!(store.get("long").await.unwrap(), None);
+        assert_eq!(store.get("a").await.unwrap().as_deref(), Some("{ a }"));
+        assert_eq!(store.get("b").await.unwrap(), None);
+        assert_eq!(PersistedQueryModel::count(&pool).await.unwrap(), 1);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn strictの場合_保存先に登録しないこと(pool: DbPool) {
+        let operations = HashMap::from([(sha256_hash(QUERY), QUERY.to_string())]);
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(PersistedQueries::new(
+                Arc::new(DatabaseStore::new(pool.clone(), 10, 1000)),
+                operations,
+                true,
+            ))
+            .finish();
+
+        assert!(
+            schema
+                .execute(request(QUERY, &sha256_hash(QUERY)))
+                .await
+                .errors
+                .is_empty()
+        );
+
+        assert_eq!(PersistedQueryModel::count(&pool).await.unwrap(), 0);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn from_config_strictでクエリを読み込めない場合_エラーになること(
+        pool: DbPool,
+    ) {
+        let config = PersistedQueriesConfig {
+            operations_dir: Some("not_found".into()),
+            strict: true,
+            ..Default::default()
+        };
+
+        assert!(PersistedQueries::from_config(&config, pool.clone()).is_err());
+        let config = PersistedQueriesConfig {
+            strict: false,
+            ..config
+        };
+        assert!(PersistedQueries::from_config(&config, pool).is_ok());
+    }
+
+    #[test]
+    fn load_operations_gqlファイルを読み込むこと() {
+        let operations = load_operations(Path::new("graphql")).unwrap();
+
+        let query = fs::read_to_string("graphql/queries/latest_resignation.gql").unwrap();
+        assert_eq!(operations.get(&sha256_hash(&query)), Some(&query));
+    }
+}
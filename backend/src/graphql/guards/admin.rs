@@ -0,0 +1,14 @@
+use async_graphql::{Context, Guard, Result};
+
+use crate::{errors::AppError, infrastructure::actor::Actor};
+
+pub struct AdminGuard;
+
+impl Guard for AdminGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        match ctx.data_opt::<Actor>() {
+            Some(actor) if actor.is_admin => Ok(()),
+            _ => Err(AppError::Forbidden.into()),
+        }
+    }
+}
@@ -1,2 +1,6 @@
+pub mod handover_task;
 pub mod post_resignation;
+pub mod resignation_status;
+pub mod revert_resignation;
 pub mod root;
+pub mod update_resignation;
@@ -0,0 +1,322 @@
+use async_graphql::{Context, ID, InputObject, Object, Result};
+use chrono::NaiveDate;
+
+use crate::{
+    graphql::{
+        objects::handover_task::HandoverTask as HandoverTaskObject,
+        validations::working_days::working_days_before,
+    },
+    infrastructure::{config::HolidaysConfig, database::DbPool},
+    models::{
+        handover_task::{
+            self, HandoverTask as HandoverTaskModel, HandoverTaskInput, HandoverTaskUpdate,
+        },
+        holiday::Holiday,
+        resignation::Resignation as ResignationModel,
+    },
+};
+
+#[derive(Default)]
+pub struct HandoverTaskMutation;
+
+#[derive(InputObject)]
+struct CreateHandoverTaskInput {
+    resignation_id: ID,
+    #[graphql(validator(min_length = 1, max_length = 255))]
+    title: String,
+    working_days_before: i32,
+}
+
+#[derive(InputObject)]
+struct UpdateHandoverTaskInput {
+    #[graphql(validator(min_length = 1, max_length = 255))]
+    title: Option<String>,
+    working_days_before: Option<i32>,
+    completed: Option<bool>,
+}
+
+/// 保存する前に期限を計算する。計算できない場合は保存せずにエラーを返す
+async fn due_date(
+    ctx: &Context<'_>,
+    resignation_id: i32,
+    working_days_before: u32,
+) -> Result<NaiveDate> {
+    let pool = ctx.data::<DbPool>()?;
+    let resignation = ResignationModel::fetch(pool, resignation_id).await?;
+    let holidays = Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, resignation.calendar).await?;
+    let vacation_start_date = resignation.covered_vacation_start_date(&holidays)?;
+
+    Ok(handover_task::due_date(
+        vacation_start_date,
+        working_days_before,
+        &holidays,
+    )?)
+}
+
+#[Object]
+impl HandoverTaskMutation {
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn create_handover_task(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateHandoverTaskInput,
+    ) -> Result<HandoverTaskObject> {
+        let pool = ctx.data::<DbPool>()?;
+        let handover_task_input = HandoverTaskInput {
+            resignation_id: input.resignation_id.parse()?,
+            title: input.title,
+            working_days_before: working_days_before(input.working_days_before)?,
+        };
+        let due_date = due_date(
+            ctx,
+            handover_task_input.resignation_id,
+            handover_task_input.working_days_before,
+        )
+        .await?;
+        let handover_task = HandoverTaskModel::insert(pool, &handover_task_input).await?;
+
+        Ok(HandoverTaskObject::from_model(handover_task, due_date))
+    }
+
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn update_handover_task(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        input: UpdateHandoverTaskInput,
+    ) -> Result<HandoverTaskObject> {
+        let pool = ctx.data::<DbPool>()?;
+        let id = id.parse()?;
+        let working_days_before = input
+            .working_days_before
+            .map(working_days_before)
+            .transpose()?;
+        let handover_task = HandoverTaskModel::fetch(pool, id).await?;
+        let due_date = due_date(
+            ctx,
+            handover_task.resignation_id,
+            working_days_before.unwrap_or(handover_task.working_days_before),
+        )
+        .await?;
+        let handover_task_update = HandoverTaskUpdate {
+            title: input.title,
+            working_days_before,
+            completed: input.completed,
+        };
+        let handover_task = HandoverTaskModel::update(pool, id, &handover_task_update).await?;
+
+        Ok(HandoverTaskObject::from_model(handover_task, due_date))
+    }
+
+    async fn delete_handover_task(&self, ctx: &Context<'_>, id: ID) -> Result<ID> {
+        let pool = ctx.data::<DbPool>()?;
+        let handover_task = HandoverTaskModel::delete(pool, id.parse()?).await?;
+
+        Ok(ID(handover_task.id.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use anyhow::{Ok, Result};
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use graphql_parser::parse_query;
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use chrono::NaiveDate;
+
+    use crate::{
+        calendars::Calendar,
+        consts::paid_leave::MAX_VACATION_SEARCH_DAYS,
+        infrastructure::database::DbPool,
+        models::{
+            handover_task::HandoverTask,
+            resignation::{Resignation, ResignationInput},
+        },
+        tests::{mocks::server::MockServer, utils::client::client},
+    };
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../../fixtures/resignation/resignations.sql")
+    )]
+    async fn create_handover_task_200(pool: DbPool) -> Result<()> {
+        let server = MockServer::new_async().await;
+        let path = "/api/v1/date.json";
+        let json_body = json!({
+            "2025-01-01": "休み",
+            "2024-12-31": "休み"
+        });
+        {
+            let mut srv = server.0.lock().await;
+            srv.mock("GET", path)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(json_body.to_string())
+                .create_async()
+                .await;
+            let (addr, client) = client(pool.clone()).await;
+            let query = parse_query::<String>(&fs::read_to_string(
+                "graphql/mutations/create_handover_task.gql",
+            )?)?
+            .to_string();
+            let variables = json!({
+                "input": {
+                    "resignationId": "2222",
+                    "title": "アカウント移管",
+                    "workingDaysBefore": 1
+                }
+            });
+
+            let response = client
+                .request(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("http://{addr}/graphql"))
+                        .header("Host", "localhost")
+                        .header("Content-Type", "application/json")
+                        .body(Body::from(
+                            json!({
+                                "query": query,
+                                "variables": variables
+                            })
+                            .to_string(),
+                        ))?,
+                )
+                .await?;
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await?.to_bytes();
+            let body: Value = serde_json::from_slice(&bytes)?;
+            assert!(body.get("errors").is_none());
+            let handover_task = &body["data"]["createHandoverTask"];
+            assert_eq!(handover_task["resignationId"], json!("2222"));
+            assert_eq!(handover_task["title"], json!("アカウント移管"));
+            assert_eq!(handover_task["dueDate"], json!("2024-12-23"));
+            assert_eq!(handover_task["completed"], json!(false));
+            let handover_tasks = HandoverTask::fetch_by_resignation_id(&pool, 2222).await?;
+            assert_eq!(handover_tasks.len(), 1);
+            srv.reset();
+        }
+
+        Ok(())
+    }
+
+    async fn create_handover_task(pool: DbPool, variables: Value) -> Result<Value> {
+        let server = MockServer::new_async().await;
+        let mut srv = server.0.lock().await;
+        srv.mock("GET", "/api/v1/date.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "2025-01-01": "休み" }).to_string())
+            .create_async()
+            .await;
+        let (addr, client) = client(pool).await;
+        let query = fs::read_to_string("graphql/mutations/create_handover_task.gql")?;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+        srv.reset();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await?.to_bytes();
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../../fixtures/resignation/resignations.sql")
+    )]
+    async fn create_handover_task_200_営業日数が上限を超える場合(
+        pool: DbPool,
+    ) -> Result<()> {
+        let body = create_handover_task(
+            pool.clone(),
+            json!({
+                "input": {
+                    "resignationId": "2222",
+                    "title": "アカウント移管",
+                    "workingDaysBefore": MAX_VACATION_SEARCH_DAYS + 1
+                }
+            }),
+        )
+        .await?;
+
+        assert!(body["data"].is_null());
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("VALIDATION_FAILED")
+        );
+        assert_eq!(
+            body["errors"][0]["message"],
+            json!(format!(
+                "期限は休暇開始日の 0 営業日前から {MAX_VACATION_SEARCH_DAYS} 営業日前までにしてください"
+            ))
+        );
+        assert!(
+            HandoverTask::fetch_by_resignation_id(&pool, 2222)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_handover_task_200_期限を計算できない場合_保存しないこと(
+        pool: DbPool,
+    ) -> Result<()> {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            remaining_paid_leave_days: 0,
+            calendar: Calendar::Jp,
+        };
+        let resignation = Resignation::insert(&pool, &input, None).await?;
+
+        let body = create_handover_task(
+            pool.clone(),
+            json!({
+                "input": {
+                    "resignationId": resignation.id.to_string(),
+                    "title": "アカウント移管",
+                    "workingDaysBefore": 1
+                }
+            }),
+        )
+        .await?;
+
+        assert!(body["data"].is_null());
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("NO_PAID_LEAVE")
+        );
+        assert!(
+            HandoverTask::fetch_by_resignation_id(&pool, resignation.id)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
@@ -1,13 +1,23 @@
-use async_graphql::{Context, ID, InputObject, Object, Result};
-use sqlx::{MySql, Pool};
+use async_graphql::{Context, InputObject, Object, Result};
 
 use crate::{
+    calendars::Calendar,
+    consts::idempotency_key::IDEMPOTENCY_KEY_MAX_LENGTH,
+    errors::AppError,
     graphql::{
-        objects::resignation::Resignation as ResignationObject,
-        scalars::{date::Date, datetime::DateTime},
-        validations::date::FutureDateValidator,
+        objects::resignation::Resignation as ResignationObject, scalars::date::Date,
+        validations::paid_leave::remaining_paid_leave_days,
     },
-    models::resignation::{Resignation as ResignationModel, ResignationInput},
+    infrastructure::{
+        config::{HolidaysConfig, PaidLeaveConfig},
+        database::DbPool,
+        idempotency_key::IdempotencyKeyHeader,
+    },
+    models::{
+        holiday::Holiday,
+        resignation::{Resignation as ResignationModel, ResignationInput},
+    },
+    utils::time::now,
 };
 
 #[derive(Default)]
@@ -15,33 +25,75 @@ pub struct PostResignationMutation;
 
 #[derive(InputObject)]
 struct PostResignationInput {
-    #[graphql(validator(custom = "FutureDateValidator"))]
+    #[graphql(desc = "明日以降の日付")]
     retirement_date: Date,
-    remaining_paid_leave_days: u32,
+    remaining_paid_leave_days: i32,
+    #[graphql(desc = "休日のカレンダー (JP, US-CA, DE-BY など)。省略した場合は JP")]
+    calendar: Option<String>,
 }
 
 #[Object]
 impl PostResignationMutation {
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
     async fn post_resignation(
         &self,
         ctx: &Context<'_>,
         input: PostResignationInput,
+        #[graphql(
+            desc = "省略した場合は Idempotency-Key ヘッダーの値を使う。同じキーでの再送では、登録したときの退職届の内容を返す"
+        )]
+        idempotency_key: Option<String>,
     ) -> Result<ResignationObject> {
-        let pool = ctx.data::<Pool<MySql>>()?;
-        let resignation_input = ResignationInput {
+        let pool = ctx.data::<DbPool>()?;
+        let calendar = match input.calendar {
+            Some(code) => code.parse()?,
+            None => Calendar::default(),
+        };
+        let paid_leave_config = ctx.data::<PaidLeaveConfig>()?;
+        let mut resignation_input = ResignationInput {
             retirement_date: input.retirement_date.0,
-            remaining_paid_leave_days: input.remaining_paid_leave_days,
+            remaining_paid_leave_days: remaining_paid_leave_days(
+                input.remaining_paid_leave_days,
+                paid_leave_config,
+            )?,
+            calendar,
+        };
+        resignation_input.validate(paid_leave_config, now().date())?;
+        let idempotency_key = idempotency_key.or_else(|| {
+            ctx.data_opt::<IdempotencyKeyHeader>()
+                .map(|idempotency_key| idempotency_key.0.clone())
+        });
+        if idempotency_key
+            .as_ref()
+            .is_some_and(|idempotency_key| idempotency_key.len() > IDEMPOTENCY_KEY_MAX_LENGTH)
+        {
+            return Err(AppError::IdempotencyKeyTooLong {
+                max: IDEMPOTENCY_KEY_MAX_LENGTH,
+            }
+            .into());
+        }
+        let holidays = Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, calendar)
+            .await
+            .ok();
+        let adjustment = resignation_input.apply_retirement_date_policy(
+            paid_leave_config.retirement_date_policy,
+            holidays.as_deref(),
+            now().date(),
+        )?;
+        let resignation = match idempotency_key {
+            Some(idempotency_key) => {
+                ResignationModel::insert_idempotently(
+                    pool,
+                    &resignation_input,
+                    holidays.as_deref(),
+                    &idempotency_key,
+                )
+                .await?
+            }
+            None => ResignationModel::insert(pool, &resignation_input, holidays.as_deref()).await?,
         };
-        let resignation = ResignationModel::insert(pool, &resignation_input).await?;
-
-        let resignation = ResignationObject::new(
-            ID(resignation.id.to_string()),
-            Date(resignation.retirement_date),
-            input.remaining_paid_leave_days,
-            DateTime(resignation.created_at),
-        );
 
-        Ok(resignation)
+        Ok(ResignationObject::from_model(resignation).with_retirement_date_adjustment(adjustment))
     }
 }
 
@@ -58,18 +110,19 @@ mod tests {
     use graphql_parser::parse_query;
     use http_body_util::BodyExt;
     use serde_json::{Value, json};
-    use sqlx::MySqlPool;
-
-    use crate::{models::resignation::Resignation, tests::utils::client::client};
-
-    #[sqlx::test]
-    async fn post_resignation_200(pool: MySqlPool) -> Result<()> {
-        let resignations = sqlx::query_as!(
-            Resignation,
-            "SELECT id, created_at, remaining_paid_leave_days, retirement_date FROM resignation"
-        )
-        .fetch_all(&pool)
-        .await?;
+
+    use crate::{
+        infrastructure::{
+            config::{Config, HolidayProvider, RetirementDatePolicy},
+            database::DbPool,
+        },
+        models::resignation::Resignation,
+        tests::utils::client::{client, client_with_config},
+    };
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn post_resignation_200(pool: DbPool) -> Result<()> {
+        let resignations = Resignation::fetch_all(&pool).await?;
         assert_eq!(resignations.len(), 0);
         let (addr, client) = client(pool.clone()).await;
         let query =
@@ -116,13 +169,9 @@ mod tests {
             *resignation.get("retirementDate").unwrap(),
             json!("9999-01-01")
         );
+        assert_eq!(*resignation.get("status").unwrap(), json!("DRAFT"));
         assert!(resignation.get("createdAt").unwrap().is_string());
-        let resignations = sqlx::query_as!(
-            Resignation,
-            "SELECT id, created_at, remaining_paid_leave_days, retirement_date FROM resignation"
-        )
-        .fetch_all(&pool)
-        .await?;
+        let resignations = Resignation::fetch_all(&pool).await?;
         assert_eq!(resignations.len(), 1);
         assert_eq!(resignations.first().unwrap().remaining_paid_leave_days, 10);
         assert_eq!(
@@ -133,14 +182,9 @@ mod tests {
         Ok(())
     }
 
-    #[sqlx::test]
-    async fn post_resignation_200_error(pool: MySqlPool) -> Result<()> {
-        let resignations = sqlx::query_as!(
-            Resignation,
-            "SELECT id, created_at, remaining_paid_leave_days, retirement_date FROM resignation"
-        )
-        .fetch_all(&pool)
-        .await?;
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn post_resignation_200_error(pool: DbPool) -> Result<()> {
+        let resignations = Resignation::fetch_all(&pool).await?;
         assert_eq!(resignations.len(), 0);
         let (addr, client) = client(pool.clone()).await;
         let query =
@@ -179,14 +223,161 @@ mod tests {
         let errors = &body["errors"];
         assert!(errors.is_array());
         assert!(errors.as_array().iter().len() > 0);
-        let resignations = sqlx::query_as!(
-            Resignation,
-            "SELECT id, created_at, remaining_paid_leave_days, retirement_date FROM resignation"
-        )
-        .fetch_all(&pool)
-        .await?;
+        assert_eq!(
+            errors[0]["message"],
+            json!("未来の日付を指定してください (指定された日付: 2000年1月1日)")
+        );
+        let resignations = Resignation::fetch_all(&pool).await?;
         assert_eq!(resignations.len(), 0);
 
         Ok(())
     }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn post_resignation_200_有給日数が上限を超える場合(
+        pool: DbPool,
+    ) -> Result<()> {
+        let (addr, client) = client(pool.clone()).await;
+        let query =
+            parse_query::<String>(&fs::read_to_string("graphql/mutations/resignation.gql")?)?
+                .to_string();
+        let variables = json!({
+            "input": {
+                "retirementDate": "9999-01-01",
+                "remainingPaidLeaveDays": 41
+            }
+        });
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await?.to_bytes();
+        let body: Value = serde_json::from_slice(&bytes)?;
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("VALIDATION_FAILED")
+        );
+        let resignations = Resignation::fetch_all(&pool).await?;
+        assert_eq!(resignations.len(), 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn post_resignation_200_退職日が休日の場合_翌営業日にずらすこと(
+        pool: DbPool,
+    ) -> Result<()> {
+        let mut config = Config::default();
+        config.holidays.provider = HolidayProvider::Offline;
+        config.paid_leave.retirement_date_policy = RetirementDatePolicy::NextBusinessDay;
+        let (addr, client) = client_with_config(pool.clone(), config).await;
+        let query =
+            parse_query::<String>(&fs::read_to_string("graphql/mutations/resignation.gql")?)?
+                .to_string();
+        let variables = json!({
+            "input": {
+                "retirementDate": "2099-01-01",
+                "remainingPaidLeaveDays": 10
+            }
+        });
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await?.to_bytes();
+        let body: Value = serde_json::from_slice(&bytes)?;
+        let resignation = &body["data"]["postResignation"];
+        assert_eq!(resignation["retirementDate"], json!("2099-01-02"));
+        assert_eq!(
+            resignation["retirementDateAdjustment"],
+            json!({
+                "policy": "NEXT_BUSINESS_DAY",
+                "requestedDate": "2099-01-01",
+                "adjustedDate": "2099-01-02"
+            })
+        );
+        let resignations = Resignation::fetch_all(&pool).await?;
+        assert_eq!(
+            resignations.first().unwrap().retirement_date,
+            NaiveDate::from_ymd_opt(2099, 1, 2).unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn post_resignation_200_idempotency_key(pool: DbPool) -> Result<()> {
+        let (addr, client) = client(pool.clone()).await;
+        let query =
+            parse_query::<String>(&fs::read_to_string("graphql/mutations/resignation.gql")?)?
+                .to_string();
+        let variables = json!({
+            "input": {
+                "retirementDate": "9999-01-01",
+                "remainingPaidLeaveDays": 10
+            }
+        });
+
+        let mut ids = vec![];
+        for _ in 0..2 {
+            let response = client
+                .request(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("http://{addr}/graphql"))
+                        .header("Host", "localhost")
+                        .header("Content-Type", "application/json")
+                        .header("Idempotency-Key", "post-resignation-key")
+                        .body(Body::from(
+                            json!({
+                                "query": query,
+                                "variables": variables
+                            })
+                            .to_string(),
+                        ))?,
+                )
+                .await?;
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await?.to_bytes();
+            let body: Value = serde_json::from_slice(&bytes)?;
+            assert!(body.get("errors").is_none());
+            ids.push(body["data"]["postResignation"]["id"].clone());
+        }
+
+        assert_eq!(ids[0], ids[1]);
+        let resignations = Resignation::fetch_all(&pool).await?;
+        assert_eq!(resignations.len(), 1);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,178 @@
+use async_graphql::{Context, ID, InputObject, Object, Result};
+
+use crate::{
+    graphql::objects::resignation::Resignation as ResignationObject,
+    infrastructure::database::DbPool,
+    models::resignation::{Resignation as ResignationModel, ResignationStatus},
+};
+
+#[derive(Default)]
+pub struct ResignationStatusMutation;
+
+#[derive(InputObject)]
+struct TransitionResignationInput {
+    id: ID,
+    #[graphql(validator(min_length = 1, max_length = 255))]
+    changed_by: String,
+    comment: Option<String>,
+}
+
+async fn transition(
+    ctx: &Context<'_>,
+    input: TransitionResignationInput,
+    to: ResignationStatus,
+) -> Result<ResignationObject> {
+    let pool = ctx.data::<DbPool>()?;
+    let resignation = ResignationModel::transition(
+        pool,
+        input.id.parse()?,
+        to,
+        &input.changed_by,
+        input.comment.as_deref(),
+    )
+    .await?;
+
+    Ok(ResignationObject::from_model(resignation))
+}
+
+#[Object]
+impl ResignationStatusMutation {
+    async fn submit_resignation(
+        &self,
+        ctx: &Context<'_>,
+        input: TransitionResignationInput,
+    ) -> Result<ResignationObject> {
+        transition(ctx, input, ResignationStatus::Submitted).await
+    }
+
+    async fn approve_resignation(
+        &self,
+        ctx: &Context<'_>,
+        input: TransitionResignationInput,
+    ) -> Result<ResignationObject> {
+        transition(ctx, input, ResignationStatus::Approved).await
+    }
+
+    async fn reject_resignation(
+        &self,
+        ctx: &Context<'_>,
+        input: TransitionResignationInput,
+    ) -> Result<ResignationObject> {
+        transition(ctx, input, ResignationStatus::Rejected).await
+    }
+
+    async fn withdraw_resignation(
+        &self,
+        ctx: &Context<'_>,
+        input: TransitionResignationInput,
+    ) -> Result<ResignationObject> {
+        transition(ctx, input, ResignationStatus::Withdrawn).await
+    }
+
+    async fn complete_resignation(
+        &self,
+        ctx: &Context<'_>,
+        input: TransitionResignationInput,
+    ) -> Result<ResignationObject> {
+        transition(ctx, input, ResignationStatus::Completed).await
+    }
+
+    async fn reopen_resignation(
+        &self,
+        ctx: &Context<'_>,
+        input: TransitionResignationInput,
+    ) -> Result<ResignationObject> {
+        transition(ctx, input, ResignationStatus::Draft).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use anyhow::{Ok, Result};
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use graphql_parser::parse_query;
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{infrastructure::database::DbPool, tests::utils::client::client};
+
+    async fn submit(pool: DbPool, id: &str) -> Result<Value> {
+        let (addr, client) = client(pool).await;
+        let query = parse_query::<String>(&fs::read_to_string(
+            "graphql/mutations/submit_resignation.gql",
+        )?)?
+        .to_string();
+        let variables = json!({
+            "input": {
+                "id": id,
+                "changedBy": "yamada",
+                "comment": "提出します"
+            }
+        });
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await?.to_bytes();
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../../fixtures/resignation/resignations.sql")
+    )]
+    async fn submit_resignation_200(pool: DbPool) -> Result<()> {
+        let body = submit(pool, "2222").await?;
+
+        assert!(body.get("errors").is_none());
+        let resignation = &body["data"]["submitResignation"];
+        assert_eq!(resignation["status"], json!("SUBMITTED"));
+        let status_history = resignation["statusHistory"].as_array().unwrap();
+        assert_eq!(status_history.len(), 1);
+        assert_eq!(status_history[0]["fromStatus"], json!("DRAFT"));
+        assert_eq!(status_history[0]["toStatus"], json!("SUBMITTED"));
+        assert_eq!(status_history[0]["changedBy"], json!("yamada"));
+        assert_eq!(status_history[0]["comment"], json!("提出します"));
+
+        Ok(())
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../../fixtures/resignation/resignations.sql")
+    )]
+    async fn submit_resignation_200_error(pool: DbPool) -> Result<()> {
+        sqlx::query("UPDATE resignation SET status = 'completed' WHERE id = 2222")
+            .execute(&pool)
+            .await?;
+
+        let body = submit(pool, "2222").await?;
+
+        assert!(body["data"].is_null());
+        let errors = &body["errors"];
+        assert!(errors.is_array());
+        assert!(errors.as_array().iter().len() > 0);
+
+        Ok(())
+    }
+}
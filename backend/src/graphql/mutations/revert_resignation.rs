@@ -0,0 +1,253 @@
+use async_graphql::{Context, ID, Object, Result};
+
+use crate::{
+    graphql::objects::resignation::Resignation as ResignationObject,
+    infrastructure::{
+        config::{HolidaysConfig, PaidLeaveConfig},
+        database::DbPool,
+    },
+    models::{holiday::Holiday, resignation::Resignation as ResignationModel},
+    utils::time::now,
+};
+
+#[derive(Default)]
+pub struct RevertResignationMutation;
+
+#[Object]
+impl RevertResignationMutation {
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn revert_resignation(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        revision: u32,
+    ) -> Result<ResignationObject> {
+        let pool = ctx.data::<DbPool>()?;
+        let id = id.parse()?;
+        let mut resignation_input = ResignationModel::revision_input(pool, id, revision).await?;
+        let paid_leave_config = ctx.data::<PaidLeaveConfig>()?;
+        resignation_input.validate(paid_leave_config, now().date())?;
+        let holidays =
+            Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, resignation_input.calendar)
+                .await
+                .ok();
+        let adjustment = resignation_input.apply_retirement_date_policy(
+            paid_leave_config.retirement_date_policy,
+            holidays.as_deref(),
+            now().date(),
+        )?;
+        let resignation =
+            ResignationModel::update(pool, id, &resignation_input, holidays.as_deref()).await?;
+
+        Ok(ResignationObject::from_model(resignation).with_retirement_date_adjustment(adjustment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use anyhow::{Ok, Result};
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use chrono::NaiveDate;
+    use graphql_parser::parse_query;
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{
+        calendars::Calendar,
+        infrastructure::{
+            config::{Config, HolidayProvider, RetirementDatePolicy},
+            database::DbPool,
+        },
+        models::{
+            resignation::{Resignation, ResignationInput},
+            resignation_revision::ResignationRevision,
+        },
+        tests::utils::client::{client, client_with_config},
+    };
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn revert_resignation_200(pool: DbPool) -> Result<()> {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(9999, 1, 1).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        };
+        let resignation = Resignation::insert(&pool, &input, None).await?;
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(9999, 2, 1).unwrap(),
+            remaining_paid_leave_days: 20,
+            calendar: Calendar::Jp,
+        };
+        Resignation::update(&pool, resignation.id, &input, None).await?;
+        let (addr, client) = client(pool.clone()).await;
+        let query = parse_query::<String>(&fs::read_to_string(
+            "graphql/mutations/revert_resignation.gql",
+        )?)?
+        .to_string();
+        let variables = json!({
+            "id": resignation.id.to_string(),
+            "revision": 1
+        });
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await?.to_bytes();
+        let body: Value = serde_json::from_slice(&bytes)?;
+        assert!(body.get("errors").is_none());
+        let resignation = &body["data"]["revertResignation"];
+        assert_eq!(resignation["retirementDate"], json!("9999-01-01"));
+        assert_eq!(resignation["remainingPaidLeaveDays"], json!(10));
+        let revisions = resignation["revisions"].as_array().unwrap();
+        assert_eq!(revisions.len(), 3);
+        assert_eq!(revisions[1]["retirementDate"], json!("9999-02-01"));
+        assert_eq!(revisions[2]["revision"], json!(3));
+        assert_eq!(revisions[2]["retirementDate"], json!("9999-01-01"));
+        assert_eq!(revisions[2]["calendar"], json!("JP"));
+
+        Ok(())
+    }
+
+    async fn revert_resignation(pool: DbPool, config: Config, variables: Value) -> Result<Value> {
+        let (addr, client) = client_with_config(pool, config).await;
+        let query = fs::read_to_string("graphql/mutations/revert_resignation.gql")?;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await?.to_bytes();
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn revert_resignation_200_戻す退職日が過去の場合(pool: DbPool) -> Result<()> {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2000, 1, 31).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        };
+        let resignation = Resignation::insert(&pool, &input, None).await?;
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(9999, 2, 1).unwrap(),
+            remaining_paid_leave_days: 20,
+            calendar: Calendar::Jp,
+        };
+        Resignation::update(&pool, resignation.id, &input, None).await?;
+
+        let body = revert_resignation(
+            pool.clone(),
+            Config::default(),
+            json!({
+                "id": resignation.id.to_string(),
+                "revision": 1
+            }),
+        )
+        .await?;
+
+        assert!(body["data"].is_null());
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("VALIDATION_FAILED")
+        );
+        let revisions = ResignationRevision::fetch_by_resignation_id(&pool, resignation.id).await?;
+        assert_eq!(revisions.len(), 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn revert_resignation_200_戻す退職日が休日の場合_設定に従うこと(
+        pool: DbPool,
+    ) -> Result<()> {
+        // 2099-01-01 は元日
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2099, 1, 1).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        };
+        let resignation = Resignation::insert(&pool, &input, None).await?;
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2099, 2, 2).unwrap(),
+            remaining_paid_leave_days: 20,
+            calendar: Calendar::Jp,
+        };
+        Resignation::update(&pool, resignation.id, &input, None).await?;
+        let config = |retirement_date_policy| {
+            let mut config = Config::default();
+            config.holidays.provider = HolidayProvider::Offline;
+            config.paid_leave.retirement_date_policy = retirement_date_policy;
+            config
+        };
+        let variables = json!({
+            "id": resignation.id.to_string(),
+            "revision": 1
+        });
+
+        let rejected = revert_resignation(
+            pool.clone(),
+            config(RetirementDatePolicy::Reject),
+            variables.clone(),
+        )
+        .await?;
+        let adjusted = revert_resignation(
+            pool.clone(),
+            config(RetirementDatePolicy::NextBusinessDay),
+            variables,
+        )
+        .await?;
+
+        assert_eq!(
+            rejected["errors"][0]["extensions"]["code"],
+            json!("VALIDATION_FAILED")
+        );
+        let resignation = &adjusted["data"]["revertResignation"];
+        assert_eq!(resignation["retirementDate"], json!("2099-01-02"));
+        assert_eq!(
+            resignation["retirementDateAdjustment"],
+            json!({
+                "policy": "NEXT_BUSINESS_DAY",
+                "requestedDate": "2099-01-01",
+                "adjustedDate": "2099-01-02"
+            })
+        );
+        assert_eq!(resignation["revisions"].as_array().unwrap().len(), 3);
+
+        Ok(())
+    }
+}
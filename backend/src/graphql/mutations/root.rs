@@ -1,5 +1,15 @@
-use super::post_resignation::PostResignationMutation;
+use super::{
+    handover_task::HandoverTaskMutation, post_resignation::PostResignationMutation,
+    resignation_status::ResignationStatusMutation, revert_resignation::RevertResignationMutation,
+    update_resignation::UpdateResignationMutation,
+};
 use async_graphql::MergedObject;
 
 #[derive(MergedObject, Default)]
-pub struct MutationRoot(PostResignationMutation);
+pub struct MutationRoot(
+    PostResignationMutation,
+    UpdateResignationMutation,
+    RevertResignationMutation,
+    HandoverTaskMutation,
+    ResignationStatusMutation,
+);
@@ -0,0 +1,69 @@
+use async_graphql::{Context, ID, InputObject, Object, Result};
+
+use crate::{
+    graphql::{
+        objects::resignation::Resignation as ResignationObject, scalars::date::Date,
+        validations::paid_leave::remaining_paid_leave_days,
+    },
+    infrastructure::{
+        config::{HolidaysConfig, PaidLeaveConfig},
+        database::DbPool,
+    },
+    models::{
+        holiday::Holiday,
+        resignation::{Resignation as ResignationModel, ResignationInput},
+    },
+    utils::time::now,
+};
+
+#[derive(Default)]
+pub struct UpdateResignationMutation;
+
+#[derive(InputObject)]
+struct UpdateResignationInput {
+    #[graphql(desc = "明日以降の日付")]
+    retirement_date: Date,
+    remaining_paid_leave_days: i32,
+    #[graphql(desc = "休日のカレンダー (JP, US-CA, DE-BY など)。省略した場合は変更しない")]
+    calendar: Option<String>,
+}
+
+#[Object]
+impl UpdateResignationMutation {
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn update_resignation(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        input: UpdateResignationInput,
+    ) -> Result<ResignationObject> {
+        let pool = ctx.data::<DbPool>()?;
+        let id = id.parse()?;
+        let calendar = match input.calendar {
+            Some(code) => code.parse()?,
+            None => ResignationModel::fetch(pool, id).await?.calendar,
+        };
+        let paid_leave_config = ctx.data::<PaidLeaveConfig>()?;
+        let mut resignation_input = ResignationInput {
+            retirement_date: input.retirement_date.0,
+            remaining_paid_leave_days: remaining_paid_leave_days(
+                input.remaining_paid_leave_days,
+                paid_leave_config,
+            )?,
+            calendar,
+        };
+        resignation_input.validate(paid_leave_config, now().date())?;
+        let holidays = Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, calendar)
+            .await
+            .ok();
+        let adjustment = resignation_input.apply_retirement_date_policy(
+            paid_leave_config.retirement_date_policy,
+            holidays.as_deref(),
+            now().date(),
+        )?;
+        let resignation =
+            ResignationModel::update(pool, id, &resignation_input, holidays.as_deref()).await?;
+
+        Ok(ResignationObject::from_model(resignation).with_retirement_date_adjustment(adjustment))
+    }
+}
@@ -1 +1,7 @@
+pub mod audit_log;
+pub mod handover_task;
+pub mod holiday;
 pub mod resignation;
+pub mod resignation_revision;
+pub mod resignation_status_history;
+pub mod retirement_date_adjustment;
@@ -0,0 +1,31 @@
+use async_graphql::{ID, SimpleObject};
+
+use crate::{graphql::scalars::datetime::DateTime, models::audit_log::AuditLog as AuditLogModel};
+
+#[derive(SimpleObject)]
+pub struct AuditLog {
+    id: ID,
+    operation_name: String,
+    variables: String,
+    /// `X-Actor` で名乗った名前。認証していないので、本人とは限らない
+    claimed_actor: Option<String>,
+    client_ip: Option<String>,
+    succeeded: bool,
+    errors: Option<String>,
+    created_at: DateTime,
+}
+
+impl AuditLog {
+    pub fn from_model(audit_log: AuditLogModel) -> Self {
+        Self {
+            id: ID(audit_log.id.to_string()),
+            operation_name: audit_log.operation_name,
+            variables: audit_log.variables,
+            claimed_actor: audit_log.claimed_actor,
+            client_ip: audit_log.client_ip,
+            succeeded: audit_log.succeeded,
+            errors: audit_log.errors,
+            created_at: DateTime(audit_log.created_at),
+        }
+    }
+}
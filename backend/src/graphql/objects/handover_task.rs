@@ -0,0 +1,53 @@
+use async_graphql::{ID, SimpleObject};
+use chrono::NaiveDate;
+
+use crate::{
+    graphql::scalars::{date::Date, datetime::DateTime},
+    models::handover_task::HandoverTask as HandoverTaskModel,
+};
+
+#[derive(SimpleObject)]
+pub struct HandoverTask {
+    id: ID,
+    resignation_id: ID,
+    title: String,
+    working_days_before: u32,
+    due_date: Date,
+    completed: bool,
+    created_at: DateTime,
+}
+
+impl HandoverTask {
+    pub fn new(
+        id: ID,
+        resignation_id: ID,
+        title: String,
+        working_days_before: u32,
+        due_date: Date,
+        completed: bool,
+        created_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            resignation_id,
+            title,
+            working_days_before,
+            due_date,
+            completed,
+            created_at,
+        }
+    }
+
+    /// `due_date` は `HandoverTaskModel::due_date` で計算した期限
+    pub fn from_model(handover_task: HandoverTaskModel, due_date: NaiveDate) -> Self {
+        Self::new(
+            ID(handover_task.id.to_string()),
+            ID(handover_task.resignation_id.to_string()),
+            handover_task.title,
+            handover_task.working_days_before,
+            Date(due_date),
+            handover_task.completed,
+            DateTime(handover_task.created_at),
+        )
+    }
+}
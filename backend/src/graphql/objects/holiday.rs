@@ -0,0 +1,23 @@
+use async_graphql::SimpleObject;
+
+use crate::{
+    graphql::{enums::holiday_source::HolidaySource, scalars::date::Date},
+    models::holiday::Holiday as HolidayModel,
+};
+
+#[derive(SimpleObject)]
+pub struct Holiday {
+    date: Date,
+    name: String,
+    source: HolidaySource,
+}
+
+impl Holiday {
+    pub fn from_model(holiday: HolidayModel) -> Self {
+        Self {
+            date: Date(holiday.date),
+            name: holiday.name,
+            source: holiday.source.into(),
+        }
+    }
+}
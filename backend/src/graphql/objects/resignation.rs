@@ -1,13 +1,36 @@
-use async_graphql::{ID, SimpleObject};
+use async_graphql::{ComplexObject, Context, ID, Result, SimpleObject};
 
-use crate::graphql::scalars::{date::Date, datetime::DateTime};
+use crate::{
+    graphql::{
+        enums::resignation_status::ResignationStatus,
+        objects::{
+            resignation_revision::ResignationRevision as ResignationRevisionObject,
+            resignation_status_history::ResignationStatusHistory as ResignationStatusHistoryObject,
+            retirement_date_adjustment::RetirementDateAdjustment as RetirementDateAdjustmentObject,
+        },
+        scalars::{date::Date, datetime::DateTime},
+    },
+    infrastructure::database::DbPool,
+    models::{
+        handover_task::HandoverTask as HandoverTaskModel,
+        resignation::{Resignation as ResignationModel, RetirementDateAdjustment},
+        resignation_revision::ResignationRevision as ResignationRevisionModel,
+        resignation_status_history::ResignationStatusHistory as ResignationStatusHistoryModel,
+    },
+};
 
 #[derive(SimpleObject)]
+#[graphql(complex)]
 pub struct Resignation {
     id: ID,
     retirement_date: Date,
     remaining_paid_leave_days: u32,
+    /// 休日のカレンダー (JP, US-CA, DE-BY など)
+    calendar: String,
+    status: ResignationStatus,
     created_at: DateTime,
+    /// 登録や更新で退職日をずらした場合の内容。取得系のクエリでは常に null
+    retirement_date_adjustment: Option<RetirementDateAdjustmentObject>,
 }
 
 impl Resignation {
@@ -15,13 +38,84 @@ impl Resignation {
         id: ID,
         retirement_date: Date,
         remaining_paid_leave_days: u32,
+        calendar: String,
+        status: ResignationStatus,
         created_at: DateTime,
     ) -> Self {
         Self {
             id,
             retirement_date,
             remaining_paid_leave_days,
+            calendar,
+            status,
             created_at,
+            retirement_date_adjustment: None,
         }
     }
+
+    pub fn from_model(resignation: ResignationModel) -> Self {
+        Self::new(
+            ID(resignation.id.to_string()),
+            Date(resignation.retirement_date),
+            resignation.remaining_paid_leave_days,
+            resignation.calendar.code().to_string(),
+            resignation.status.into(),
+            DateTime(resignation.created_at),
+        )
+    }
+
+    pub fn with_retirement_date_adjustment(
+        mut self,
+        adjustment: Option<RetirementDateAdjustment>,
+    ) -> Self {
+        self.retirement_date_adjustment =
+            adjustment.map(RetirementDateAdjustmentObject::from_model);
+        self
+    }
+}
+
+#[ComplexObject]
+impl Resignation {
+    async fn handover_completion_percentage(&self, ctx: &Context<'_>) -> Result<f64> {
+        let pool = ctx.data::<DbPool>()?;
+        let completion_percentage =
+            HandoverTaskModel::completion_percentage(pool, self.id.parse()?).await?;
+
+        Ok(completion_percentage)
+    }
+
+    async fn status_history(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<Vec<ResignationStatusHistoryObject>> {
+        let pool = ctx.data::<DbPool>()?;
+        let status_history =
+            ResignationStatusHistoryModel::fetch_by_resignation_id(pool, self.id.parse()?)
+                .await?
+                .into_iter()
+                .map(|history| {
+                    ResignationStatusHistoryObject::new(
+                        ID(history.id.to_string()),
+                        history.from_status.into(),
+                        history.to_status.into(),
+                        history.changed_by,
+                        history.comment,
+                        DateTime(history.changed_at),
+                    )
+                })
+                .collect();
+
+        Ok(status_history)
+    }
+
+    async fn revisions(&self, ctx: &Context<'_>) -> Result<Vec<ResignationRevisionObject>> {
+        let pool = ctx.data::<DbPool>()?;
+        let revisions = ResignationRevisionModel::fetch_by_resignation_id(pool, self.id.parse()?)
+            .await?
+            .into_iter()
+            .map(ResignationRevisionObject::from_model)
+            .collect();
+
+        Ok(revisions)
+    }
 }
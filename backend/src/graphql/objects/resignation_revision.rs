@@ -0,0 +1,32 @@
+use async_graphql::{ID, SimpleObject};
+
+use crate::{
+    graphql::scalars::{date::Date, datetime::DateTime},
+    models::resignation_revision::ResignationRevision as ResignationRevisionModel,
+};
+
+#[derive(SimpleObject)]
+pub struct ResignationRevision {
+    id: ID,
+    revision: u32,
+    retirement_date: Date,
+    remaining_paid_leave_days: u32,
+    vacation_start_date: Option<Date>,
+    /// 記録した時点の休日のカレンダー
+    calendar: String,
+    created_at: DateTime,
+}
+
+impl ResignationRevision {
+    pub fn from_model(resignation_revision: ResignationRevisionModel) -> Self {
+        Self {
+            id: ID(resignation_revision.id.to_string()),
+            revision: resignation_revision.revision,
+            retirement_date: Date(resignation_revision.retirement_date),
+            remaining_paid_leave_days: resignation_revision.remaining_paid_leave_days,
+            vacation_start_date: resignation_revision.vacation_start_date.map(Date),
+            calendar: resignation_revision.calendar.code().to_string(),
+            created_at: DateTime(resignation_revision.created_at),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use async_graphql::{ID, SimpleObject};
+
+use crate::graphql::{enums::resignation_status::ResignationStatus, scalars::datetime::DateTime};
+
+#[derive(SimpleObject)]
+pub struct ResignationStatusHistory {
+    id: ID,
+    from_status: ResignationStatus,
+    to_status: ResignationStatus,
+    changed_by: String,
+    comment: Option<String>,
+    changed_at: DateTime,
+}
+
+impl ResignationStatusHistory {
+    pub fn new(
+        id: ID,
+        from_status: ResignationStatus,
+        to_status: ResignationStatus,
+        changed_by: String,
+        comment: Option<String>,
+        changed_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            from_status,
+            to_status,
+            changed_by,
+            comment,
+            changed_at,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use async_graphql::SimpleObject;
+
+use crate::{
+    graphql::{enums::retirement_date_policy::RetirementDatePolicy, scalars::date::Date},
+    models::resignation::RetirementDateAdjustment as RetirementDateAdjustmentModel,
+};
+
+/// 退職日が休日だったため、設定に従ってずらした結果
+#[derive(SimpleObject)]
+pub struct RetirementDateAdjustment {
+    policy: RetirementDatePolicy,
+    /// 入力された退職日
+    requested_date: Date,
+    /// ずらした後の退職日
+    adjusted_date: Date,
+}
+
+impl RetirementDateAdjustment {
+    pub fn from_model(adjustment: RetirementDateAdjustmentModel) -> Self {
+        Self {
+            policy: adjustment.policy.into(),
+            requested_date: Date(adjustment.requested_date),
+            adjusted_date: Date(adjustment.adjusted_date),
+        }
+    }
+}
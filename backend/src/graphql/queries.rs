@@ -1,3 +1,7 @@
+pub mod audit_logs;
+pub mod business_days;
+pub mod handover_tasks;
+pub mod holidays;
 pub mod latest_resignation;
 pub mod root;
 pub mod vacation_start_date;
@@ -0,0 +1,142 @@
+use async_graphql::{Context, InputObject, Object, Result};
+
+use crate::{
+    graphql::{
+        guards::admin::AdminGuard, objects::audit_log::AuditLog as AuditLogObject,
+        scalars::datetime::DateTime,
+    },
+    infrastructure::database::DbPool,
+    models::audit_log::{AuditLog as AuditLogModel, AuditLogFilter},
+};
+
+const DEFAULT_LIMIT: u32 = 100;
+
+#[derive(Default)]
+pub struct AuditLogsQuery;
+
+#[derive(InputObject, Default)]
+struct AuditLogsFilter {
+    operation_name: Option<String>,
+    claimed_actor: Option<String>,
+    succeeded: Option<bool>,
+    from: Option<DateTime>,
+    to: Option<DateTime>,
+    #[graphql(validator(minimum = 1, maximum = 1000))]
+    limit: Option<u32>,
+}
+
+#[Object]
+impl AuditLogsQuery {
+    #[graphql(guard = "AdminGuard")]
+    async fn audit_logs(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<AuditLogsFilter>,
+    ) -> Result<Vec<AuditLogObject>> {
+        let pool = ctx.data::<DbPool>()?;
+        let filter = filter.unwrap_or_default();
+        let filter = AuditLogFilter {
+            operation_name: filter.operation_name,
+            claimed_actor: filter.claimed_actor,
+            succeeded: filter.succeeded,
+            from: filter.from.map(|from| from.0),
+            to: filter.to.map(|to| to.0),
+            limit: filter.limit.unwrap_or(DEFAULT_LIMIT),
+        };
+        let audit_logs = AuditLogModel::fetch_all(pool, &filter)
+            .await?
+            .into_iter()
+            .map(AuditLogObject::from_model)
+            .collect();
+
+        Ok(audit_logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use graphql_parser::parse_query;
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{
+        infrastructure::database::DbPool,
+        models::audit_log::{AuditLog, AuditLogInput},
+        tests::utils::client::{ADMIN_TOKEN, client},
+    };
+
+    async fn audit_logs(pool: DbPool, authorization: Option<&str>) -> Value {
+        AuditLog::insert(
+            &pool,
+            &AuditLogInput {
+                operation_name: "post".to_string(),
+                variables: "{}".to_string(),
+                claimed_actor: Some("yamada".to_string()),
+                client_ip: Some("127.0.0.1".to_string()),
+                succeeded: true,
+                errors: None,
+            },
+        )
+        .await
+        .unwrap();
+        let (addr, client) = client(pool).await;
+        let query =
+            parse_query::<String>(&fs::read_to_string("graphql/queries/audit_logs.gql").unwrap())
+                .unwrap()
+                .to_string();
+        let mut request = Request::builder()
+            .method("POST")
+            .uri(format!("http://{addr}/graphql"))
+            .header("Host", "localhost")
+            .header("Content-Type", "application/json");
+        if let Some(authorization) = authorization {
+            request = request.header("Authorization", authorization);
+        }
+
+        let response = client
+            .request(
+                request
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": { "filter": { "claimedActor": "yamada" } }
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn audit_logs_200(pool: DbPool) {
+        let body = audit_logs(pool, Some(&format!("Bearer {ADMIN_TOKEN}"))).await;
+
+        assert!(body.get("errors").is_none());
+        let audit_logs = body["data"]["auditLogs"].as_array().unwrap();
+        assert_eq!(audit_logs.len(), 1);
+        assert_eq!(audit_logs[0]["operationName"], json!("post"));
+        assert_eq!(audit_logs[0]["claimedActor"], json!("yamada"));
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn audit_logs_200_管理者以外の場合_エラーになること(pool: DbPool) {
+        let body = audit_logs(pool, None).await;
+
+        assert!(body["data"].is_null());
+        let errors = &body["errors"];
+        assert!(errors.is_array());
+        assert!(errors.as_array().iter().len() > 0);
+    }
+}
@@ -0,0 +1,196 @@
+use async_graphql::{Context, Object, Result};
+
+use crate::{
+    calendars::Calendar, errors::AppError, graphql::scalars::date::Date,
+    infrastructure::config::HolidaysConfig, models::holiday::Holiday,
+};
+
+#[derive(Default)]
+pub struct BusinessDaysQuery;
+
+#[Object]
+impl BusinessDaysQuery {
+    /// `from` から `to` まで (両端を含む) の営業日の数。有給の計算と同じ休日を除く
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn business_days_between(
+        &self,
+        ctx: &Context<'_>,
+        from: Date,
+        to: Date,
+        #[graphql(desc = "休日のカレンダー (JP, US-CA, DE-BY など)。省略した場合は JP")]
+        calendar: Option<String>,
+    ) -> Result<u32> {
+        if from.0 > to.0 {
+            return Err(AppError::InvalidDateRange {
+                from: from.0,
+                to: to.0,
+            }
+            .into());
+        }
+        let calendar = match calendar {
+            Some(code) => code.parse()?,
+            None => Calendar::default(),
+        };
+        let holidays = Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, calendar).await?;
+        holidays.ensure_covered(from.0, to.0)?;
+
+        Ok(holidays.calendar().business_days_between(from.0, to.0))
+    }
+
+    /// `date` の `days` 営業日後の日付。負の数の場合は営業日前の日付。`date` 自体は数えない
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn add_business_days(
+        &self,
+        ctx: &Context<'_>,
+        date: Date,
+        #[graphql(validator(minimum = -3650, maximum = 3650))] days: i32,
+        #[graphql(desc = "休日のカレンダー (JP, US-CA, DE-BY など)。省略した場合は JP")]
+        calendar: Option<String>,
+    ) -> Result<Date> {
+        let calendar = match calendar {
+            Some(code) => code.parse()?,
+            None => Calendar::default(),
+        };
+        let holidays = Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, calendar).await?;
+        let result = if days >= 0 {
+            let result = holidays
+                .calendar()
+                .add_business_days(date.0, days.unsigned_abs())?;
+            holidays.ensure_covered(date.0, result)?;
+            result
+        } else {
+            let result = holidays
+                .calendar()
+                .sub_business_days(date.0, days.unsigned_abs())?;
+            holidays.ensure_covered(result, date.0)?;
+            result
+        };
+
+        Ok(Date(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{
+        infrastructure::{
+            config::{Config, HolidayProvider},
+            database::DbPool,
+        },
+        tests::utils::client::client_with_config,
+    };
+
+    async fn request(pool: DbPool, path: &str, variables: Value) -> Value {
+        let mut config = Config::default();
+        config.holidays.provider = HolidayProvider::Offline;
+        let (addr, client) = client_with_config(pool, config).await;
+        let query = fs::read_to_string(path).unwrap();
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "query": query,
+                            "variables": variables
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn business_days_between_200(pool: DbPool) {
+        // 2025-12-29 から 2026-01-09 のうち、土日と元日を除いた日
+        let body = request(
+            pool,
+            "graphql/queries/business_days_between.gql",
+            json!({ "from": "2025-12-29", "to": "2026-01-09" }),
+        )
+        .await;
+
+        assert_eq!(body["data"]["businessDaysBetween"], json!(9));
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn business_days_between_200_日付の範囲が逆の場合(pool: DbPool) {
+        let body = request(
+            pool,
+            "graphql/queries/business_days_between.gql",
+            json!({ "from": "2026-01-09", "to": "2025-12-29" }),
+        )
+        .await;
+
+        assert!(body["data"].is_null());
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("VALIDATION_FAILED")
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn add_business_days_200(pool: DbPool) {
+        let path = "graphql/queries/add_business_days.gql";
+
+        let next = request(
+            pool.clone(),
+            path,
+            json!({ "date": "2025-12-31", "days": 1 }),
+        )
+        .await;
+        let previous = request(
+            pool.clone(),
+            path,
+            json!({ "date": "2026-01-02", "days": -1 }),
+        )
+        .await;
+        let us_ca = request(
+            pool,
+            path,
+            json!({ "date": "2025-11-26", "days": 1, "calendar": "US-CA" }),
+        )
+        .await;
+
+        assert_eq!(next["data"]["addBusinessDays"], json!("2026-01-02"));
+        assert_eq!(previous["data"]["addBusinessDays"], json!("2025-12-31"));
+        // 感謝祭 (2025-11-27) とその翌日を飛ばす
+        assert_eq!(us_ca["data"]["addBusinessDays"], json!("2025-12-01"));
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn add_business_days_200_ずらす日数が上限を超える場合(pool: DbPool) {
+        let body = request(
+            pool,
+            "graphql/queries/add_business_days.gql",
+            json!({ "date": "2025-12-31", "days": 3651 }),
+        )
+        .await;
+
+        assert!(body["data"].is_null());
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("VALIDATION_FAILED")
+        );
+    }
+}
@@ -0,0 +1,9 @@
+INSERT INTO
+  resignation (
+    id,
+    retirement_date,
+    remaining_paid_leave_days,
+    created_at
+  )
+VALUES
+  (1111, '2100-01-08', 10, '2025-01-01 00:00:00');
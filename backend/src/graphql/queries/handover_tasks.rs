@@ -0,0 +1,123 @@
+use async_graphql::{Context, ID, Object, Result};
+
+use crate::{
+    graphql::objects::handover_task::HandoverTask as HandoverTaskObject,
+    infrastructure::{config::HolidaysConfig, database::DbPool},
+    models::{
+        handover_task::HandoverTask as HandoverTaskModel, holiday::Holiday,
+        resignation::Resignation as ResignationModel,
+    },
+};
+
+#[derive(Default)]
+pub struct HandoverTasksQuery;
+
+#[Object]
+impl HandoverTasksQuery {
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn handover_tasks(
+        &self,
+        ctx: &Context<'_>,
+        resignation_id: ID,
+    ) -> Result<Vec<HandoverTaskObject>> {
+        let pool = ctx.data::<DbPool>()?;
+        let resignation_id = resignation_id.parse()?;
+        let resignation = ResignationModel::fetch(pool, resignation_id).await?;
+        let holidays =
+            Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, resignation.calendar).await?;
+        let vacation_start_date = resignation.covered_vacation_start_date(&holidays)?;
+        let handover_tasks = HandoverTaskModel::fetch_by_resignation_id(pool, resignation_id)
+            .await?
+            .into_iter()
+            .map(|handover_task| {
+                let due_date = handover_task.due_date(vacation_start_date, &holidays)?;
+
+                Ok(HandoverTaskObject::from_model(handover_task, due_date))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(handover_tasks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use graphql_parser::parse_query;
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{
+        infrastructure::database::DbPool,
+        tests::{mocks::server::MockServer, utils::client::client},
+    };
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures(
+            "../../fixtures/resignation/resignations.sql",
+            "../../fixtures/handover_task/handover_tasks.sql"
+        )
+    )]
+    async fn handover_tasks_200(pool: DbPool) {
+        let server = MockServer::new_async().await;
+        let path = "/api/v1/date.json";
+        let json_body = json!({
+            "2025-01-01": "休み",
+            "2024-12-31": "休み"
+        });
+        {
+            let mut srv = server.0.lock().await;
+            srv.mock("GET", path)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(json_body.to_string())
+                .create_async()
+                .await;
+            let (addr, client) = client(pool).await;
+            let query = parse_query::<String>(
+                &fs::read_to_string("graphql/queries/handover_tasks.gql").unwrap(),
+            )
+            .unwrap()
+            .to_string();
+
+            let response = client
+                .request(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("http://{addr}/graphql"))
+                        .header("Host", "localhost")
+                        .header("Content-Type", "application/json")
+                        .body(Body::from(
+                            json!({
+                                "query": query,
+                                "variables": { "resignationId": "2222" }
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body: Value = serde_json::from_slice(&bytes).unwrap();
+            assert!(body.get("errors").is_none());
+            let handover_tasks = body["data"]["handoverTasks"].as_array().unwrap();
+            assert_eq!(handover_tasks.len(), 2);
+            // 有給 5 日: 2024-12-30, 2024-12-27, 2024-12-26, 2024-12-25, 2024-12-24
+            // 休暇開始日 2024-12-24 の 5 営業日前: 2024-12-17
+            assert_eq!(handover_tasks[0]["dueDate"], json!("2024-12-17"));
+            assert_eq!(handover_tasks[0]["completed"], json!(true));
+            assert_eq!(handover_tasks[1]["dueDate"], json!("2024-12-23"));
+            assert_eq!(handover_tasks[1]["completed"], json!(false));
+            srv.reset();
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use async_graphql::{Context, Object, Result};
+
+use crate::{
+    calendars::Calendar,
+    errors::AppError,
+    graphql::{objects::holiday::Holiday as HolidayObject, scalars::date::Date},
+    infrastructure::config::HolidaysConfig,
+    models::holiday::Holiday as HolidayModel,
+};
+
+#[derive(Default)]
+pub struct HolidaysQuery;
+
+#[Object]
+impl HolidaysQuery {
+    /// `from` から `to` まで (両端を含む) の祝日。有給の計算と同じ祝日を返す
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
+    async fn holidays(
+        &self,
+        ctx: &Context<'_>,
+        from: Date,
+        to: Date,
+        #[graphql(desc = "休日のカレンダー (JP, US-CA, DE-BY など)。省略した場合は JP")]
+        calendar: Option<String>,
+    ) -> Result<Vec<HolidayObject>> {
+        if from.0 > to.0 {
+            return Err(AppError::InvalidDateRange {
+                from: from.0,
+                to: to.0,
+            }
+            .into());
+        }
+        let calendar = match calendar {
+            Some(code) => code.parse()?,
+            None => Calendar::default(),
+        };
+        let holidays =
+            HolidayModel::fetch_between(ctx.data::<HolidaysConfig>()?, calendar, from.0, to.0)
+                .await?
+                .into_iter()
+                .map(HolidayObject::from_model)
+                .collect();
+
+        Ok(holidays)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use chrono::NaiveDate;
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{
+        infrastructure::{
+            config::{CompanyHoliday, Config},
+            database::DbPool,
+        },
+        tests::{mocks::server::MockServer, utils::client::client_with_config},
+    };
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn holidays_200(pool: DbPool) {
+        let server = MockServer::new_async().await;
+        let path = "/api/v1/date.json";
+        let json_body = json!({
+            "2024-12-23": "休み",
+            "2025-01-01": "元日",
+            "2025-01-13": "成人の日"
+        });
+        {
+            let mut srv = server.0.lock().await;
+            srv.mock("GET", path)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(json_body.to_string())
+                .create_async()
+                .await;
+            let mut config = Config::default();
+            config.holidays.company = vec![CompanyHoliday {
+                date: NaiveDate::from_ymd_opt(2025, 1, 3).unwrap(),
+                name: "年始休暇".to_string(),
+            }];
+            let (addr, client) = client_with_config(pool, config).await;
+            let query = fs::read_to_string("graphql/queries/holidays.gql").unwrap();
+
+            let response = client
+                .request(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("http://{addr}/graphql"))
+                        .header("Host", "localhost")
+                        .header("Content-Type", "application/json")
+                        .body(Body::from(
+                            json!({
+                                "query": query,
+                                "variables": { "from": "2025-01-01", "to": "2025-01-10" },
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(
+                body["data"]["holidays"],
+                json!([
+                    { "date": "2025-01-01", "name": "元日", "source": "NATIONAL" },
+                    { "date": "2025-01-03", "name": "年始休暇", "source": "COMPANY" },
+                ])
+            );
+            srv.reset();
+        }
+    }
+}
@@ -1,12 +1,8 @@
-use async_graphql::{Context, ID, Object, Result};
-use sqlx::{MySql, Pool};
+use async_graphql::{Context, Object, Result};
 
 use crate::{
-    graphql::{
-        objects::resignation::Resignation as ResignationObject,
-        scalars::{date::Date, datetime::DateTime},
-    },
-    models::resignation::Resignation as ResignationModel,
+    graphql::objects::resignation::Resignation as ResignationObject,
+    infrastructure::database::DbPool, models::resignation::Resignation as ResignationModel,
 };
 
 #[derive(Default)]
@@ -15,15 +11,10 @@ pub struct LatestResignationQuery;
 #[Object]
 impl LatestResignationQuery {
     async fn latest_resignation(&self, ctx: &Context<'_>) -> Result<ResignationObject> {
-        let pool = ctx.data::<Pool<MySql>>().unwrap();
+        let pool = ctx.data::<DbPool>().unwrap();
         let latest_resignation = ResignationModel::fetch_latest(pool).await?;
 
-        Ok(ResignationObject::new(
-            ID(latest_resignation.id.to_string()),
-            Date(latest_resignation.retirement_date),
-            latest_resignation.remaining_paid_leave_days,
-            DateTime(latest_resignation.created_at),
-        ))
+        Ok(ResignationObject::from_model(latest_resignation))
     }
 }
 
@@ -38,12 +29,14 @@ mod tests {
     use graphql_parser::parse_query;
     use http_body_util::BodyExt;
     use serde_json::{Value, json};
-    use sqlx::MySqlPool;
 
-    use crate::tests::utils::client::client;
+    use crate::{infrastructure::database::DbPool, tests::utils::client::client};
 
-    #[sqlx::test(fixtures("../../fixtures/resignation/resignations.sql"))]
-    async fn latest_resignation_200(pool: MySqlPool) {
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../../fixtures/resignation/resignations.sql")
+    )]
+    async fn latest_resignation_200(pool: DbPool) {
         let (addr, client) = client(pool).await;
         let query = parse_query::<String>(
             &fs::read_to_string("graphql/queries/latest_resignation.gql").unwrap(),
@@ -83,5 +76,11 @@ mod tests {
             *resignation.get("createdAt").unwrap(),
             json!("2025-02-01 00:00:00")
         );
+        assert_eq!(*resignation.get("calendar").unwrap(), json!("JP"));
+        assert_eq!(*resignation.get("status").unwrap(), json!("DRAFT"));
+        assert_eq!(
+            *resignation.get("handoverCompletionPercentage").unwrap(),
+            json!(0.0)
+        );
     }
 }
@@ -1,8 +1,17 @@
 use async_graphql::MergedObject;
 
 use super::{
+    audit_logs::AuditLogsQuery, business_days::BusinessDaysQuery,
+    handover_tasks::HandoverTasksQuery, holidays::HolidaysQuery,
     latest_resignation::LatestResignationQuery, vacation_start_date::VacationStartDateQuery,
 };
 
 #[derive(MergedObject, Default)]
-pub struct QueryRoot(LatestResignationQuery, VacationStartDateQuery);
+pub struct QueryRoot(
+    LatestResignationQuery,
+    VacationStartDateQuery,
+    HandoverTasksQuery,
+    HolidaysQuery,
+    BusinessDaysQuery,
+    AuditLogsQuery,
+);
@@ -1,9 +1,9 @@
 use async_graphql::{Context, Object, Result};
-use sqlx::{MySql, Pool};
 
 use crate::{
-    clients::holidays::fetch_holidays, graphql::scalars::date::Date,
-    models::resignation::Resignation as ResignationModel,
+    graphql::scalars::date::Date,
+    infrastructure::{config::HolidaysConfig, database::DbPool},
+    models::{holiday::Holiday, resignation::Resignation as ResignationModel},
 };
 
 #[derive(Default)]
@@ -11,13 +11,13 @@ pub struct VacationStartDateQuery;
 
 #[Object]
 impl VacationStartDateQuery {
+    #[graphql(complexity = "crate::graphql::HOLIDAY_FETCH_COMPLEXITY + child_complexity")]
     async fn vacation_start_date(&self, ctx: &Context<'_>) -> Result<Date> {
-        let holidays = fetch_holidays().await?;
-        let pool = ctx.data::<Pool<MySql>>().unwrap();
-        let vacation_start_date = ResignationModel::fetch_latest(pool)
-            .await?
-            .vacation_start_date(&holidays)
-            .await?;
+        let pool = ctx.data::<DbPool>().unwrap();
+        let resignation = ResignationModel::fetch_latest(pool).await?;
+        let holidays =
+            Holiday::fetch_map(ctx.data::<HolidaysConfig>()?, resignation.calendar).await?;
+        let vacation_start_date = resignation.covered_vacation_start_date(&holidays)?;
 
         Ok(Date(vacation_start_date))
     }
@@ -34,12 +34,23 @@ mod tests {
     use graphql_parser::parse_query;
     use http_body_util::BodyExt;
     use serde_json::{Value, json};
-    use sqlx::MySqlPool;
 
-    use crate::tests::{mocks::server::MockServer, utils::client::client};
+    use crate::{
+        infrastructure::{
+            config::{Config, HolidayProvider},
+            database::DbPool,
+        },
+        tests::{
+            mocks::server::MockServer,
+            utils::client::{client, client_with_config},
+        },
+    };
 
-    #[sqlx::test(fixtures("vacation_start_date_200_data"))]
-    async fn vacation_start_date_200_data(pool: MySqlPool) {
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("vacation_start_date_200_data")
+    )]
+    async fn vacation_start_date_200_data(pool: DbPool) {
         let server = MockServer::new_async().await;
         let path = "/api/v1/date.json";
         let json_body = json!({
@@ -88,8 +99,8 @@ mod tests {
         }
     }
 
-    #[sqlx::test()]
-    async fn vacation_start_date_200_err(pool: MySqlPool) {
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn vacation_start_date_200_err(pool: DbPool) {
         let server = MockServer::new_async().await;
         let path = "/api/v1/date.json";
         let json_body = json!("invalid");
@@ -132,4 +143,42 @@ mod tests {
             srv.reset();
         }
     }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("vacation_start_date_not_covered")
+    )]
+    async fn vacation_start_date_祝日のデータがない年の場合_エラーになること(
+        pool: DbPool,
+    ) {
+        let mut config = Config::default();
+        config.holidays.provider = HolidayProvider::Offline;
+        let (addr, client) = client_with_config(pool, config).await;
+        let query = parse_query::<String>(
+            &fs::read_to_string("graphql/queries/vacation_start_date.gql").unwrap(),
+        )
+        .unwrap()
+        .to_string();
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(json!({"query": query}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("HOLIDAYS_NOT_COVERED")
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
 use chrono::NaiveDate;
 
+use crate::errors::AppError;
+
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub struct Date(pub NaiveDate);
 
@@ -8,9 +10,11 @@ pub struct Date(pub NaiveDate);
 impl ScalarType for Date {
     fn parse(value: Value) -> InputValueResult<Self> {
         if let Value::String(value) = &value {
-            let date = value
-                .parse::<NaiveDate>()
-                .map_err(|e| InputValueError::custom(format!("無効な Date: {}", e)))?;
+            let date = value.parse::<NaiveDate>().map_err(|_| {
+                InputValueError::custom(AppError::InvalidDateFormat {
+                    value: value.clone(),
+                })
+            })?;
             Ok(Date(date))
         } else {
             Err(InputValueError::expected_type(value))
@@ -1,6 +1,8 @@
 use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
 use chrono::NaiveDateTime;
 
+use crate::errors::AppError;
+
 #[derive(Debug, PartialEq, PartialOrd)]
 pub struct DateTime(pub NaiveDateTime);
 
@@ -8,8 +10,12 @@ pub struct DateTime(pub NaiveDateTime);
 impl ScalarType for DateTime {
     fn parse(value: Value) -> InputValueResult<Self> {
         if let Value::String(value) = &value {
-            let datetime = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
-                .map_err(|e| InputValueError::custom(format!("無効な DateTime: {}", e)))?;
+            let datetime =
+                NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").map_err(|_| {
+                    InputValueError::custom(AppError::InvalidDateTimeFormat {
+                        value: value.clone(),
+                    })
+                })?;
             Ok(DateTime(datetime))
         } else {
             Err(InputValueError::expected_type(value))
@@ -1 +1,2 @@
-pub mod date;
+pub mod paid_leave;
+pub mod working_days;
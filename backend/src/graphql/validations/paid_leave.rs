@@ -0,0 +1,29 @@
+use crate::{errors::AppError, infrastructure::config::PaidLeaveConfig};
+
+/// GraphQL の Int で受け取った残りの有給日数。負の数は `u32` に変換できないので、ここで弾く
+///
+/// 上限は `ResignationInput::validate` でも確かめるが、範囲外のエラーは同じ内容で返す。
+pub fn remaining_paid_leave_days(value: i32, config: &PaidLeaveConfig) -> Result<u32, AppError> {
+    u32::try_from(value).map_err(|_| AppError::PaidLeaveDaysOutOfRange {
+        max: config.max_remaining_days,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn 有給がない場合_エラーにならないこと() {
+        let result = remaining_paid_leave_days(0, &PaidLeaveConfig::default());
+
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[test]
+    fn 負の数の場合_エラーになること() {
+        let result = remaining_paid_leave_days(-1, &PaidLeaveConfig::default());
+
+        assert_eq!(result.unwrap_err().code(), "VALIDATION_FAILED");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{consts::paid_leave::MAX_VACATION_SEARCH_DAYS, errors::AppError};
+
+/// 引き継ぎの期限を休暇開始日の何営業日前にするか。休暇開始日を探すときと同じ日数までにする
+///
+/// async-graphql のバリデーターで弾くとメッセージが英語になるため、リゾルバーで確かめて `AppError` を返す。
+pub fn working_days_before(value: i32) -> Result<u32, AppError> {
+    u32::try_from(value)
+        .ok()
+        .filter(|value| *value <= MAX_VACATION_SEARCH_DAYS)
+        .ok_or(AppError::WorkingDaysBeforeOutOfRange {
+            max: MAX_VACATION_SEARCH_DAYS,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn 上限の場合_エラーにならないこと() {
+        let result = working_days_before(MAX_VACATION_SEARCH_DAYS as i32);
+
+        assert_eq!(result.unwrap(), MAX_VACATION_SEARCH_DAYS);
+    }
+
+    #[test]
+    fn 上限を超える場合_エラーになること() {
+        let result = working_days_before(MAX_VACATION_SEARCH_DAYS as i32 + 1);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn 負の数の場合_エラーになること() {
+        let result = working_days_before(-1);
+
+        assert!(result.is_err());
+    }
+}
@@ -1,2 +1,11 @@
+pub mod actor;
 pub mod app;
+pub mod config;
 pub mod database;
+pub mod health;
+pub mod idempotency_key;
+pub mod locale;
+pub mod logging;
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
@@ -0,0 +1,190 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::http::{HeaderMap, header};
+use subtle::ConstantTimeEq;
+
+pub const ACTOR_HEADER: &str = "x-actor";
+pub const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// `X-Forwarded-For` を信用するリバースプロキシの IP
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies(pub Vec<IpAddr>);
+
+/// リクエストを送った接続元の IP。
+/// `X-Forwarded-For` は誰でも付けられるので、信用するプロキシからの接続でだけ読み、
+/// 右から辿って最初に現れた信用しない IP を接続元とする
+pub fn client_ip(
+    headers: &HeaderMap,
+    remote_addr: SocketAddr,
+    trusted_proxies: &[IpAddr],
+) -> IpAddr {
+    let mut client_ip = remote_addr.ip();
+    if !trusted_proxies.contains(&client_ip) {
+        return client_ip;
+    }
+
+    let forwarded_for = headers
+        .get_all(FORWARDED_FOR_HEADER)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|value| value.trim().parse::<IpAddr>().ok())
+        .collect::<Vec<_>>();
+    for ip in forwarded_for.into_iter().rev() {
+        // 読めない値より左は確かめようがないので、その右隣までで止める
+        let Some(ip) = ip else {
+            break;
+        };
+        client_ip = ip;
+        if !trusted_proxies.contains(&ip) {
+            break;
+        }
+    }
+
+    client_ip
+}
+
+/// リクエストを送った利用者
+#[derive(Debug, Clone, Default)]
+pub struct Actor {
+    /// `X-Actor` で名乗った名前。認証していないので、本人とは限らない
+    pub claimed_name: Option<String>,
+    pub client_ip: Option<String>,
+    pub is_admin: bool,
+}
+
+impl Actor {
+    pub fn from_request(
+        headers: &HeaderMap,
+        remote_addr: SocketAddr,
+        admin_token: Option<&str>,
+        trusted_proxies: &[IpAddr],
+    ) -> Self {
+        let claimed_name = headers
+            .get(ACTOR_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let client_ip = client_ip(headers, remote_addr, trusted_proxies);
+        let bearer_token = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        // 一致するまでの時間からトークンを推測されないよう、一定時間で比べる
+        let is_admin = matches!(
+            (admin_token, bearer_token),
+            (Some(admin_token), Some(bearer_token))
+                if bool::from(admin_token.as_bytes().ct_eq(bearer_token.as_bytes()))
+        );
+
+        Self {
+            claimed_name,
+            client_ip: Some(client_ip.to_string()),
+            is_admin,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn remote_addr() -> SocketAddr {
+        "192.168.0.1:12345".parse().unwrap()
+    }
+
+    #[test]
+    fn from_request_ヘッダーがない場合_接続元のIPを使うこと() {
+        let actor = Actor::from_request(&HeaderMap::new(), remote_addr(), Some("secret"), &[]);
+
+        assert_eq!(actor.claimed_name, None);
+        assert_eq!(actor.client_ip.as_deref(), Some("192.168.0.1"));
+        assert!(!actor.is_admin);
+    }
+
+    #[test]
+    fn from_request_信用するプロキシからの場合_ヘッダーの値を使うこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACTOR_HEADER, HeaderValue::from_static("yamada"));
+        headers.insert(
+            FORWARDED_FOR_HEADER,
+            HeaderValue::from_static("10.0.0.1, 10.0.0.2"),
+        );
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer secret"),
+        );
+
+        let actor = Actor::from_request(
+            &headers,
+            remote_addr(),
+            Some("secret"),
+            &["192.168.0.1".parse().unwrap()],
+        );
+
+        assert_eq!(actor.claimed_name.as_deref(), Some("yamada"));
+        assert_eq!(actor.client_ip.as_deref(), Some("10.0.0.2"));
+        assert!(actor.is_admin);
+    }
+
+    #[test]
+    fn from_request_トークンが一致しない場合_管理者にならないこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer secreT"),
+        );
+
+        let actor = Actor::from_request(&headers, remote_addr(), Some("secret"), &[]);
+
+        assert!(!actor.is_admin);
+    }
+
+    #[test]
+    fn from_request_管理者トークンが未設定の場合_管理者にならないこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer "));
+
+        let actor = Actor::from_request(&headers, remote_addr(), None, &[]);
+
+        assert!(!actor.is_admin);
+    }
+
+    #[test]
+    fn client_ip_信用しないプロキシからの場合_ヘッダーを無視すること() {
+        let mut headers = HeaderMap::new();
+        headers.insert(FORWARDED_FOR_HEADER, HeaderValue::from_static("10.0.0.1"));
+
+        let client_ip = client_ip(&headers, remote_addr(), &[]);
+
+        assert_eq!(client_ip, "192.168.0.1".parse::<IpAddr>().unwrap());
+    }
+
+    #[test]
+    fn client_ip_信用するプロキシを経由した場合_その手前の接続元を使うこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            FORWARDED_FOR_HEADER,
+            HeaderValue::from_static("1.1.1.1, 10.0.0.1, 192.168.0.2"),
+        );
+        let trusted_proxies = [
+            "192.168.0.1".parse().unwrap(),
+            "192.168.0.2".parse().unwrap(),
+        ];
+
+        let client_ip = client_ip(&headers, remote_addr(), &trusted_proxies);
+
+        // 左端の 1.1.1.1 は 10.0.0.1 が付けた値で、偽装されているかもしれない
+        assert_eq!(client_ip, "10.0.0.1".parse::<IpAddr>().unwrap());
+    }
+
+    #[test]
+    fn client_ip_ヘッダーが読めない場合_プロキシのIPを使うこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(FORWARDED_FOR_HEADER, HeaderValue::from_static("unknown"));
+
+        let client_ip = client_ip(&headers, remote_addr(), &["192.168.0.1".parse().unwrap()]);
+
+        assert_eq!(client_ip, "192.168.0.1".parse::<IpAddr>().unwrap());
+    }
+}
@@ -1,40 +1,193 @@
-use async_graphql::{EmptySubscription, Schema, http::GraphiQLSource};
-use async_graphql_axum::GraphQL;
+use std::net::SocketAddr;
+
+use async_graphql::{
+    EmptySubscription, Schema, SchemaBuilder, extensions::Tracing, http::GraphiQLSource,
+};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
 use axum::{
-    Router,
-    http::{HeaderValue, Method, header},
+    Extension, Router,
+    extract::{ConnectInfo, Request},
+    http::{HeaderMap, HeaderName, HeaderValue, Method, header},
+    middleware,
     response::{Html, IntoResponse},
-    routing::get,
+    routing::{get, post},
 };
 
-use sqlx::MySqlPool;
-use tower_http::cors::CorsLayer;
+use tower_http::{
+    cors::{AllowOrigin, CorsLayer},
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::TraceLayer,
+};
+
+use crate::{
+    graphql::{
+        extensions::{
+            audit_log::AuditLog, error_code::ErrorCode, metrics::Metrics,
+            persisted_queries::PersistedQueries,
+        },
+        mutations::root::MutationRoot,
+        queries::root::QueryRoot,
+    },
+    infrastructure::{
+        actor::{ACTOR_HEADER, Actor, TrustedProxies},
+        config::Config,
+        database::DbPool,
+        health::{HolidaysCheckCache, healthz, readyz},
+        idempotency_key::{IDEMPOTENCY_KEY_HEADER, IdempotencyKeyHeader},
+        locale::{LOCALE_HEADER, Locale},
+        metrics::{metrics, track_http},
+        rate_limit::{RateLimiter, rate_limit},
+        request_id::{REQUEST_ID_HEADER, RequestId},
+    },
+    rest,
+};
 
-use crate::graphql::{mutations::root::MutationRoot, queries::root::QueryRoot};
+pub type AppSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+#[derive(Clone)]
+struct AdminToken(Option<String>);
 
 async fn graphiql() -> impl IntoResponse {
     Html(GraphiQLSource::build().endpoint("/graphql").finish())
 }
 
-pub fn app(pool: MySqlPool) -> Router {
-    let schema = Schema::build(
+async fn graphql(
+    Extension(schema): Extension<AppSchema>,
+    Extension(AdminToken(admin_token)): Extension<AdminToken>,
+    Extension(TrustedProxies(trusted_proxies)): Extension<TrustedProxies>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let actor = Actor::from_request(
+        &headers,
+        remote_addr,
+        admin_token.as_deref(),
+        &trusted_proxies,
+    );
+    let request_id = RequestId::from_headers(&headers);
+    let locale = Locale::from_headers(&headers);
+    let mut request = request.into_inner().data(actor);
+    if let Some(idempotency_key) = IdempotencyKeyHeader::from_headers(&headers) {
+        request = request.data(idempotency_key);
+    }
+
+    // バリデーターやスカラーでもメッセージの言語を選べるよう、実行全体を言語のスコープに入れる
+    let mut response = locale.scope(schema.execute(request)).await;
+    if let Some(RequestId(request_id)) = &request_id {
+        for error in &mut response.errors {
+            error
+                .extensions
+                .get_or_insert_with(Default::default)
+                .set("requestId", request_id.as_str());
+        }
+    }
+
+    response.into()
+}
+
+/// サーバーと `schema` サブコマンドで同じスキーマを使うための共通部分
+pub fn schema_builder() -> SchemaBuilder<QueryRoot, MutationRoot, EmptySubscription> {
+    Schema::build(
         QueryRoot::default(),
         MutationRoot::default(),
         EmptySubscription,
     )
-    .data(pool.clone())
-    .finish();
+    .extension(AuditLog)
+    .extension(Metrics)
+    .extension(Tracing)
+    .extension(ErrorCode)
+}
+
+pub fn app(pool: DbPool, config: Config) -> anyhow::Result<Router> {
+    let mut schema = schema_builder()
+        .data(pool.clone())
+        .data(config.holidays.clone())
+        .data(config.paid_leave.clone())
+        .limit_depth(config.graphql.max_depth)
+        .limit_complexity(config.graphql.max_complexity);
+    if config.persisted_queries.enabled {
+        schema = schema.extension(PersistedQueries::from_config(
+            &config.persisted_queries,
+            pool.clone(),
+        )?);
+    }
+    if !config.graphql.introspection {
+        schema = schema.disable_introspection();
+    }
+    let schema = schema.finish();
     let cors = CorsLayer::new()
-        .allow_origin(
-            "http://localhost:9000"
-                .parse::<HeaderValue>()
-                .unwrap_or_else(|_| HeaderValue::from_static("http://localhost:9000")),
-        )
+        .allow_origin(AllowOrigin::list(
+            config
+                .allowed_origins
+                .iter()
+                .filter_map(|origin| origin.parse::<HeaderValue>().ok()),
+        ))
         .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-        .allow_headers([header::CONTENT_TYPE, header::ACCEPT]);
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::ACCEPT,
+            header::AUTHORIZATION,
+            header::ACCEPT_LANGUAGE,
+            HeaderName::from_static(ACTOR_HEADER),
+            HeaderName::from_static(IDEMPOTENCY_KEY_HEADER),
+            HeaderName::from_static(LOCALE_HEADER),
+            HeaderName::from_static(REQUEST_ID_HEADER),
+        ])
+        .expose_headers([
+            HeaderName::from_static(REQUEST_ID_HEADER),
+            header::RETRY_AFTER,
+        ]);
+    let request_id_header = HeaderName::from_static(REQUEST_ID_HEADER);
+    let trace = TraceLayer::new_for_http().make_span_with(|request: &Request| {
+        let request_id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
 
-    Router::new()
-        .route("/graphql", get(graphiql).post_service(GraphQL::new(schema)))
+        tracing::info_span!(
+            "http_request",
+            method = %request.method(),
+            path = %request.uri().path(),
+            request_id,
+        )
+    });
+
+    let graphql_route = if config.graphql.graphiql {
+        get(graphiql).post(graphql)
+    } else {
+        post(graphql)
+    };
+    let mut api = rest::router();
+    let graphql_route = if config.rate_limit.enabled {
+        // GraphQL と REST API で同じバケツを使う
+        let limiter = RateLimiter::new(&config.rate_limit, &config.trusted_proxies);
+        api = api.route_layer(middleware::from_fn_with_state(limiter.clone(), rate_limit));
+        graphql_route.route_layer(middleware::from_fn_with_state(limiter, rate_limit))
+    } else {
+        graphql_route
+    };
+
+    let router = Router::new()
+        .route("/graphql", graphql_route)
+        .nest("/api", api)
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/metrics", get(metrics))
+        .layer(middleware::from_fn(track_http))
+        .layer(Extension(schema))
+        .layer(Extension(config.holidays))
+        .layer(Extension(HolidaysCheckCache::default()))
+        .layer(Extension(config.paid_leave))
+        .layer(Extension(AdminToken(config.admin_token)))
+        .layer(Extension(TrustedProxies(config.trusted_proxies)))
         .layer(cors)
-        .with_state(pool)
+        // 外側から順に、ID を振る → ID 付きのスパンで記録する → レスポンスに ID を返す
+        .layer(PropagateRequestIdLayer::new(request_id_header.clone()))
+        .layer(trace)
+        .layer(SetRequestIdLayer::new(request_id_header, MakeRequestUuid))
+        .with_state(pool);
+
+    Ok(router)
 }
@@ -0,0 +1,635 @@
+use std::{
+    fs,
+    net::{IpAddr, SocketAddr},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use axum::http::HeaderValue;
+use chrono::{FixedOffset, NaiveDate};
+use clap::{Args, ValueEnum};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use crate::consts::paid_leave::{
+    DEFAULT_MAX_REMAINING_PAID_LEAVE_DAYS, MAX_REMAINING_PAID_LEAVE_DAYS_LIMIT,
+};
+
+pub const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+/// サーバーの設定。既定値 < 設定ファイル (TOML) < 環境変数 < コマンドライン引数 の順に上書きする
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub listen_address: SocketAddr,
+    pub allowed_origins: Vec<String>,
+    /// `X-Forwarded-For` を信用するリバースプロキシの IP。これ以外からの接続ではヘッダーを無視する
+    pub trusted_proxies: Vec<IpAddr>,
+    /// `+09:00` のような UTC からのオフセット
+    pub timezone: String,
+    pub admin_token: Option<String>,
+    pub database: DatabaseConfig,
+    pub holidays: HolidaysConfig,
+    pub paid_leave: PaidLeaveConfig,
+    pub graphql: GraphQLConfig,
+    pub rate_limit: RateLimitConfig,
+    pub persisted_queries: PersistedQueriesConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub url: Option<String>,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout_secs: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HolidaysConfig {
+    pub provider: HolidayProvider,
+    pub base_url: String,
+    /// 国民の祝日に加えて休みにする、会社独自の休日
+    pub company: Vec<CompanyHoliday>,
+}
+
+/// 国民の祝日の取得元
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum HolidayProvider {
+    /// holidays-jp の API。API にない年は祝日法の規則から計算して補う
+    ///
+    /// API に繋がらない場合は、前回取得した祝日を使い、それもなければ祝日法の規則から計算する。
+    #[default]
+    Api,
+    /// 祝日法の規則から計算する。ネットワークに繋がない
+    Offline,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompanyHoliday {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaidLeaveConfig {
+    /// 登録できる残りの有給日数の上限。特別休暇がある場合は増やす
+    pub max_remaining_days: u32,
+    pub retirement_date_policy: RetirementDatePolicy,
+}
+
+/// 退職日が土日や休日の場合の扱い。登録と更新のときに適用する
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum RetirementDatePolicy {
+    /// そのまま登録する。退職日は有給の日数に数えない
+    #[default]
+    Allow,
+    /// 登録を断る
+    Reject,
+    /// 直前の営業日にずらす
+    PreviousBusinessDay,
+    /// 直後の営業日にずらす
+    NextBusinessDay,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GraphQLConfig {
+    pub graphiql: bool,
+    pub introspection: bool,
+    /// クエリの入れ子の深さの上限
+    pub max_depth: usize,
+    /// クエリの複雑度の上限。祝日を取得するフィールドは重く数える
+    pub max_complexity: usize,
+}
+
+/// GraphQL と REST API のエンドポイントに掛ける、接続元の IP ごとのトークンバケツ
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    /// 続けて受け付けられるリクエスト数
+    pub burst: u32,
+    /// 1 秒あたりに回復するリクエスト数
+    pub requests_per_second: f64,
+}
+
+/// 自動永続化クエリ (APQ) の設定
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PersistedQueriesConfig {
+    pub enabled: bool,
+    pub store: PersistedQueryStoreKind,
+    /// 登録するクエリ数の上限
+    pub max_entries: usize,
+    /// 登録するクエリの長さ (バイト) の上限
+    pub max_query_length: usize,
+    /// 事前に登録するクエリ (.gql) を置いたディレクトリ
+    pub operations_dir: Option<PathBuf>,
+    /// 事前に登録したクエリ以外を断る。本番環境で使う
+    pub strict: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum PersistedQueryStoreKind {
+    Memory,
+    Database,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            listen_address: SocketAddr::from(([127, 0, 0, 1], 8000)),
+            allowed_origins: vec!["http://localhost:9000".to_string()],
+            trusted_proxies: Vec::new(),
+            timezone: "+09:00".to_string(),
+            admin_token: None,
+            database: DatabaseConfig::default(),
+            holidays: HolidaysConfig::default(),
+            paid_leave: PaidLeaveConfig::default(),
+            graphql: GraphQLConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            persisted_queries: PersistedQueriesConfig::default(),
+        }
+    }
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            max_connections: 5,
+            min_connections: 0,
+            acquire_timeout_secs: 30,
+        }
+    }
+}
+
+impl Default for HolidaysConfig {
+    fn default() -> Self {
+        Self {
+            provider: HolidayProvider::default(),
+            base_url: "https://holidays-jp.github.io".to_string(),
+            company: Vec::new(),
+        }
+    }
+}
+
+impl Default for GraphQLConfig {
+    fn default() -> Self {
+        Self {
+            graphiql: true,
+            introspection: true,
+            max_depth: 10,
+            max_complexity: 200,
+        }
+    }
+}
+
+impl Default for PaidLeaveConfig {
+    fn default() -> Self {
+        Self {
+            max_remaining_days: DEFAULT_MAX_REMAINING_PAID_LEAVE_DAYS,
+            retirement_date_policy: RetirementDatePolicy::default(),
+        }
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            burst: 30,
+            requests_per_second: 5.0,
+        }
+    }
+}
+
+impl Default for PersistedQueriesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            store: PersistedQueryStoreKind::Memory,
+            max_entries: 1000,
+            max_query_length: 10_000,
+            operations_dir: None,
+            strict: false,
+        }
+    }
+}
+
+/// 設定ファイルの値を上書きするコマンドライン引数と環境変数
+#[derive(Debug, Default, Args)]
+pub struct ConfigArgs {
+    /// 設定ファイルのパス。省略時は config.toml があれば読み込む
+    #[arg(long = "config", env = "CONFIG_PATH")]
+    pub config_path: Option<PathBuf>,
+    #[arg(long, env = "LISTEN_ADDRESS")]
+    pub listen_address: Option<SocketAddr>,
+    /// カンマ区切りで複数指定できる
+    #[arg(long, env = "ALLOWED_ORIGINS", value_delimiter = ',')]
+    pub allowed_origins: Option<Vec<String>>,
+    /// カンマ区切りで複数指定できる
+    #[arg(long, env = "TRUSTED_PROXIES", value_delimiter = ',')]
+    pub trusted_proxies: Option<Vec<IpAddr>>,
+    #[arg(long, env = "TIMEZONE")]
+    pub timezone: Option<String>,
+    #[arg(long, env = "ADMIN_TOKEN", hide_env_values = true)]
+    pub admin_token: Option<String>,
+    #[arg(long, env = "DATABASE_URL", hide_env_values = true)]
+    pub database_url: Option<String>,
+    #[arg(long, env = "DATABASE_MAX_CONNECTIONS")]
+    pub database_max_connections: Option<u32>,
+    #[arg(long, env = "DATABASE_MIN_CONNECTIONS")]
+    pub database_min_connections: Option<u32>,
+    #[arg(long, env = "DATABASE_ACQUIRE_TIMEOUT_SECS")]
+    pub database_acquire_timeout_secs: Option<u64>,
+    #[arg(long, env = "HOLIDAYS_PROVIDER")]
+    pub holidays_provider: Option<HolidayProvider>,
+    #[arg(long, env = "HOLIDAYS_BASE_URL")]
+    pub holidays_base_url: Option<String>,
+    #[arg(long, env = "PAID_LEAVE_MAX_REMAINING_DAYS")]
+    pub paid_leave_max_remaining_days: Option<u32>,
+    #[arg(long, env = "PAID_LEAVE_RETIREMENT_DATE_POLICY")]
+    pub paid_leave_retirement_date_policy: Option<RetirementDatePolicy>,
+    #[arg(long, env = "GRAPHIQL")]
+    pub graphiql: Option<bool>,
+    #[arg(long, env = "INTROSPECTION")]
+    pub introspection: Option<bool>,
+    #[arg(long, env = "GRAPHQL_MAX_DEPTH")]
+    pub graphql_max_depth: Option<usize>,
+    #[arg(long, env = "GRAPHQL_MAX_COMPLEXITY")]
+    pub graphql_max_complexity: Option<usize>,
+    #[arg(long, env = "RATE_LIMIT_ENABLED")]
+    pub rate_limit_enabled: Option<bool>,
+    #[arg(long, env = "RATE_LIMIT_BURST")]
+    pub rate_limit_burst: Option<u32>,
+    #[arg(long, env = "RATE_LIMIT_REQUESTS_PER_SECOND")]
+    pub rate_limit_requests_per_second: Option<f64>,
+    #[arg(long, env = "PERSISTED_QUERIES_ENABLED")]
+    pub persisted_queries_enabled: Option<bool>,
+    #[arg(long, env = "PERSISTED_QUERIES_STORE")]
+    pub persisted_queries_store: Option<PersistedQueryStoreKind>,
+    #[arg(long, env = "PERSISTED_QUERIES_MAX_ENTRIES")]
+    pub persisted_queries_max_entries: Option<usize>,
+    #[arg(long, env = "PERSISTED_QUERIES_MAX_QUERY_LENGTH")]
+    pub persisted_queries_max_query_length: Option<usize>,
+    #[arg(long, env = "PERSISTED_QUERIES_OPERATIONS_DIR")]
+    pub persisted_queries_operations_dir: Option<PathBuf>,
+    #[arg(long, env = "PERSISTED_QUERIES_STRICT")]
+    pub persisted_queries_strict: Option<bool>,
+}
+
+impl Config {
+    pub fn load(args: ConfigArgs) -> Result<Self> {
+        let config = match &args.config_path {
+            Some(path) => Self::from_file(path)?,
+            None if fs::exists(DEFAULT_CONFIG_PATH).unwrap_or(false) => {
+                Self::from_file(Path::new(DEFAULT_CONFIG_PATH))?
+            }
+            None => Self::default(),
+        };
+        let config = config.merge(args);
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("設定ファイル {} を読み込めません", path.display()))?;
+
+        Self::from_toml(&content)
+            .with_context(|| format!("設定ファイル {} の形式が不正です", path.display()))
+    }
+
+    fn from_toml(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    fn merge(mut self, args: ConfigArgs) -> Self {
+        if let Some(listen_address) = args.listen_address {
+            self.listen_address = listen_address;
+        }
+        if let Some(allowed_origins) = args.allowed_origins {
+            self.allowed_origins = allowed_origins;
+        }
+        if let Some(trusted_proxies) = args.trusted_proxies {
+            self.trusted_proxies = trusted_proxies;
+        }
+        if let Some(timezone) = args.timezone {
+            self.timezone = timezone;
+        }
+        if let Some(admin_token) = args.admin_token {
+            self.admin_token = Some(admin_token);
+        }
+        if let Some(database_url) = args.database_url {
+            self.database.url = Some(database_url);
+        }
+        if let Some(max_connections) = args.database_max_connections {
+            self.database.max_connections = max_connections;
+        }
+        if let Some(min_connections) = args.database_min_connections {
+            self.database.min_connections = min_connections;
+        }
+        if let Some(acquire_timeout_secs) = args.database_acquire_timeout_secs {
+            self.database.acquire_timeout_secs = acquire_timeout_secs;
+        }
+        if let Some(provider) = args.holidays_provider {
+            self.holidays.provider = provider;
+        }
+        if let Some(base_url) = args.holidays_base_url {
+            self.holidays.base_url = base_url;
+        }
+        if let Some(max_remaining_days) = args.paid_leave_max_remaining_days {
+            self.paid_leave.max_remaining_days = max_remaining_days;
+        }
+        if let Some(retirement_date_policy) = args.paid_leave_retirement_date_policy {
+            self.paid_leave.retirement_date_policy = retirement_date_policy;
+        }
+        if let Some(graphiql) = args.graphiql {
+            self.graphql.graphiql = graphiql;
+        }
+        if let Some(introspection) = args.introspection {
+            self.graphql.introspection = introspection;
+        }
+        if let Some(max_depth) = args.graphql_max_depth {
+            self.graphql.max_depth = max_depth;
+        }
+        if let Some(max_complexity) = args.graphql_max_complexity {
+            self.graphql.max_complexity = max_complexity;
+        }
+        if let Some(enabled) = args.rate_limit_enabled {
+            self.rate_limit.enabled = enabled;
+        }
+        if let Some(burst) = args.rate_limit_burst {
+            self.rate_limit.burst = burst;
+        }
+        if let Some(requests_per_second) = args.rate_limit_requests_per_second {
+            self.rate_limit.requests_per_second = requests_per_second;
+        }
+        if let Some(enabled) = args.persisted_queries_enabled {
+            self.persisted_queries.enabled = enabled;
+        }
+        if let Some(store) = args.persisted_queries_store {
+            self.persisted_queries.store = store;
+        }
+        if let Some(max_entries) = args.persisted_queries_max_entries {
+            self.persisted_queries.max_entries = max_entries;
+        }
+        if let Some(max_query_length) = args.persisted_queries_max_query_length {
+            self.persisted_queries.max_query_length = max_query_length;
+        }
+        if let Some(operations_dir) = args.persisted_queries_operations_dir {
+            self.persisted_queries.operations_dir = Some(operations_dir);
+        }
+        if let Some(strict) = args.persisted_queries_strict {
+            self.persisted_queries.strict = strict;
+        }
+
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for origin in &self.allowed_origins {
+            let url = Url::parse(origin)
+                .with_context(|| format!("allowed_origins の {origin} は URL ではありません"))?;
+            if !matches!(url.scheme(), "http" | "https") || HeaderValue::from_str(origin).is_err() {
+                bail!("allowed_origins の {origin} は http(s) のオリジンではありません");
+            }
+        }
+        self.offset()?;
+        if self.database.url.is_none() {
+            bail!("database.url (DATABASE_URL) が設定されていません");
+        }
+        if self.database.max_connections == 0 {
+            bail!("database.max_connections は 1 以上にしてください");
+        }
+        if self.database.min_connections > self.database.max_connections {
+            bail!("database.min_connections は database.max_connections 以下にしてください");
+        }
+        let base_url = Url::parse(&self.holidays.base_url).with_context(|| {
+            format!(
+                "holidays.base_url の {} は URL ではありません",
+                self.holidays.base_url
+            )
+        })?;
+        if !matches!(base_url.scheme(), "http" | "https") {
+            bail!("holidays.base_url は http(s) の URL にしてください");
+        }
+        if !(1..=MAX_REMAINING_PAID_LEAVE_DAYS_LIMIT).contains(&self.paid_leave.max_remaining_days)
+        {
+            bail!(
+                "paid_leave.max_remaining_days は 1 以上 {MAX_REMAINING_PAID_LEAVE_DAYS_LIMIT} 以下にしてください"
+            );
+        }
+        if self.graphql.max_depth == 0 || self.graphql.max_complexity == 0 {
+            bail!("graphql.max_depth と graphql.max_complexity は 1 以上にしてください");
+        }
+        if self.rate_limit.enabled {
+            if self.rate_limit.burst == 0 {
+                bail!("rate_limit.burst は 1 以上にしてください");
+            }
+            if !self.rate_limit.requests_per_second.is_finite()
+                || self.rate_limit.requests_per_second <= 0.0
+            {
+                bail!("rate_limit.requests_per_second は 0 より大きくしてください");
+            }
+        }
+        if let Some(operations_dir) = &self.persisted_queries.operations_dir {
+            if !operations_dir.is_dir() {
+                bail!(
+                    "persisted_queries.operations_dir の {} はディレクトリではありません",
+                    operations_dir.display()
+                );
+            }
+        } else if self.persisted_queries.enabled && self.persisted_queries.strict {
+            bail!("persisted_queries.strict にする場合は operations_dir を設定してください");
+        }
+
+        Ok(())
+    }
+
+    pub fn offset(&self) -> Result<FixedOffset> {
+        FixedOffset::from_str(&self.timezone).map_err(|_| {
+            anyhow!(
+                "timezone の {} は +09:00 のような UTC からのオフセットではありません",
+                self.timezone
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            database: DatabaseConfig {
+                url: Some("mysql://localhost/new_place".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn from_toml() {
+        let config = Config::from_toml(
+            r#"
+            listen_address = "0.0.0.0:8080"
+            allowed_origins = ["https://example.com"]
+            trusted_proxies = ["10.0.0.1"]
+
+            [database]
+            max_connections = 10
+
+            [graphql]
+            introspection = false
+
+            [holidays]
+            provider = "offline"
+
+            [paid_leave]
+            retirement_date_policy = "next_business_day"
+
+            [[holidays.company]]
+            date = "2025-12-29"
+            name = "年末休暇"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.listen_address, "0.0.0.0:8080".parse().unwrap());
+        assert_eq!(config.allowed_origins, vec!["https://example.com"]);
+        assert_eq!(
+            config.trusted_proxies,
+            vec!["10.0.0.1".parse::<IpAddr>().unwrap()]
+        );
+        assert_eq!(config.timezone, "+09:00");
+        assert_eq!(config.database.max_connections, 10);
+        assert_eq!(config.database.acquire_timeout_secs, 30);
+        assert!(config.graphql.graphiql);
+        assert!(!config.graphql.introspection);
+        assert_eq!(config.holidays.provider, HolidayProvider::Offline);
+        assert_eq!(
+            config.paid_leave.retirement_date_policy,
+            RetirementDatePolicy::NextBusinessDay
+        );
+        assert_eq!(
+            config.holidays.company[0].date,
+            NaiveDate::from_ymd_opt(2025, 12, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_toml_未知のキーがある場合_エラーになること() {
+        assert!(Config::from_toml("listen_adress = \"0.0.0.0:8080\"").is_err());
+    }
+
+    #[test]
+    fn merge_引数がある場合_設定ファイルの値を上書きすること() {
+        let args = ConfigArgs {
+            listen_address: Some("0.0.0.0:9999".parse().unwrap()),
+            graphiql: Some(false),
+            ..Default::default()
+        };
+
+        let config = config().merge(args);
+
+        assert_eq!(config.listen_address, "0.0.0.0:9999".parse().unwrap());
+        assert!(!config.graphql.graphiql);
+        assert_eq!(config.database.max_connections, 5);
+    }
+
+    #[test]
+    fn merge_永続化クエリの引数がある場合_設定ファイルの値を上書きすること() {
+        let args = ConfigArgs {
+            persisted_queries_enabled: Some(false),
+            persisted_queries_max_entries: Some(10),
+            persisted_queries_max_query_length: Some(100),
+            ..Default::default()
+        };
+
+        let config = config().merge(args);
+
+        assert!(!config.persisted_queries.enabled);
+        assert_eq!(config.persisted_queries.max_entries, 10);
+        assert_eq!(config.persisted_queries.max_query_length, 100);
+    }
+
+    #[test]
+    fn validate() {
+        assert!(config().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_オリジンが不正な場合_エラーになること() {
+        let config = Config {
+            allowed_origins: vec!["localhost:9000".to_string()],
+            ..config()
+        };
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_タイムゾーンが不正な場合_エラーになること() {
+        let config = Config {
+            timezone: "Asia/Tokyo".to_string(),
+            ..config()
+        };
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_最小接続数が最大接続数を超える場合_エラーになること() {
+        let mut config = config();
+        config.database.min_connections = 10;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_有給日数の上限が範囲外の場合_エラーになること() {
+        let mut config = config();
+        config.paid_leave.max_remaining_days = 0;
+        assert!(config.validate().is_err());
+
+        config.paid_leave.max_remaining_days = 366;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_レート制限の回復量が0の場合_エラーになること() {
+        let mut config = config();
+        config.rate_limit.requests_per_second = 0.0;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_strictで登録するクエリがない場合_エラーになること() {
+        let mut config = config();
+        config.persisted_queries.strict = true;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_データベースのURLがない場合_エラーになること() {
+        let mut config = config();
+        config.database.url = None;
+
+        assert!(config.validate().is_err());
+    }
+}
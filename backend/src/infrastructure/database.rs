@@ -1,11 +1,51 @@
-use anyhow::Result;
-use sqlx::{MySql, Pool, mysql::MySqlPoolOptions};
-
-pub async fn get_pool() -> Result<Pool<MySql>> {
-    let database_url = dotenv::var("DATABASE_URL")?;
-    let pool = MySqlPoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use sqlx::{Database, Pool, migrate::Migrator, pool::PoolOptions};
+
+use crate::infrastructure::config::DatabaseConfig;
+
+#[cfg(any(
+    all(feature = "mysql", feature = "postgres"),
+    all(feature = "mysql", feature = "sqlite"),
+    all(feature = "postgres", feature = "sqlite"),
+))]
+compile_error!("enable only one of the `mysql`, `postgres` and `sqlite` features");
+
+#[cfg(not(any(feature = "mysql", feature = "postgres", feature = "sqlite")))]
+compile_error!("enable one of the `mysql`, `postgres` and `sqlite` features");
+
+#[cfg(feature = "mysql")]
+pub type Db = sqlx::MySql;
+#[cfg(feature = "postgres")]
+pub type Db = sqlx::Postgres;
+#[cfg(feature = "sqlite")]
+pub type Db = sqlx::Sqlite;
+
+pub type DbPool = Pool<Db>;
+pub type DbConnection = <Db as Database>::Connection;
+
+#[cfg(feature = "mysql")]
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/mysql");
+#[cfg(feature = "postgres")]
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+#[cfg(feature = "sqlite")]
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+pub async fn get_pool(config: &DatabaseConfig) -> Result<DbPool> {
+    let database_url = config
+        .url
+        .as_deref()
+        .context("database.url (DATABASE_URL) が設定されていません")?;
+    let pool = PoolOptions::<Db>::new()
+        .max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .acquire_timeout(Duration::from_secs(config.acquire_timeout_secs))
+        .connect(database_url)
         .await?;
+    // SQLite は手元で手軽に動かすための組み込み DB なので、起動時にマイグレーションも済ませる
+    #[cfg(feature = "sqlite")]
+    MIGRATOR.run(&pool).await?;
+
     Ok(pool)
 }
@@ -0,0 +1,241 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{Extension, Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    clients::holidays::{cached_holidays, fetch_holidays},
+    infrastructure::{
+        config::{HolidayProvider, HolidaysConfig},
+        database::DbPool,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Check {
+    Ok,
+    /// 取得元には繋がらないが、前回取得した祝日で計算できる
+    Cached,
+    /// 取得元に繋がらず前回取得した祝日もないが、祝日法の規則から計算できる
+    Computed,
+    Unavailable,
+}
+
+impl Check {
+    fn is_ready(self) -> bool {
+        self != Self::Unavailable
+    }
+}
+
+/// 祝日の取得元を確かめた結果を使い回す時間。プローブのたびに外部へリクエストしないようにする
+const HOLIDAYS_CHECK_TTL: Duration = Duration::from_secs(60);
+
+/// 祝日の取得元を最後に確かめた時刻と結果
+#[derive(Clone, Default)]
+pub struct HolidaysCheckCache(Arc<Mutex<Option<(Instant, Check)>>>);
+
+impl HolidaysCheckCache {
+    async fn check(&self, config: &HolidaysConfig) -> Check {
+        let cached = self
+            .0
+            .lock()
+            .ok()
+            .and_then(|cache| *cache)
+            .filter(|(checked_at, _)| checked_at.elapsed() < HOLIDAYS_CHECK_TTL);
+        if let Some((_, check)) = cached {
+            return check;
+        }
+        let check = match fetch_holidays(&config.base_url).await {
+            Ok(_) => Check::Ok,
+            Err(_) if cached_holidays().is_some() => Check::Cached,
+            Err(_) => Check::Computed,
+        };
+        if let Ok(mut cache) = self.0.lock() {
+            *cache = Some((Instant::now(), check));
+        }
+
+        check
+    }
+}
+
+/// プロセスが応答できるか
+pub async fn healthz() -> impl IntoResponse {
+    Json(json!({ "status": "ok" }))
+}
+
+/// DB が使え、リクエストを受け付けられるか
+///
+/// 祝日は取得元に繋がらなくても前回取得した祝日か規則から計算できるので、どの状態でも受け付けられるとし、
+/// どこから祝日を得るかだけを返す。
+pub async fn readyz(
+    State(pool): State<DbPool>,
+    Extension(holidays_config): Extension<HolidaysConfig>,
+    Extension(holidays_check): Extension<HolidaysCheckCache>,
+) -> impl IntoResponse {
+    let database = match sqlx::query("SELECT 1").execute(&pool).await {
+        Ok(_) => Check::Ok,
+        Err(_) => Check::Unavailable,
+    };
+    let holidays = match holidays_config.provider {
+        // 祝日は計算で求めるので、取得元に繋がらなくてもよい
+        HolidayProvider::Offline => Check::Ok,
+        HolidayProvider::Api => holidays_check.check(&holidays_config).await,
+    };
+    let (status_code, status) = if database.is_ready() && holidays.is_ready() {
+        (StatusCode::OK, "ok")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+    };
+
+    (
+        status_code,
+        Json(json!({
+            "status": status,
+            "checks": {
+                "database": database,
+                "holidays": holidays,
+            },
+        })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use hyper_util::client::legacy::{Client, connect::HttpConnector};
+    use serde_json::Value;
+
+    use crate::{
+        infrastructure::database::DbPool,
+        tests::{mocks::server::MockServer, utils::client::client},
+    };
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn healthz_200(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .uri(format!("http://{addr}/healthz"))
+                    .header("Host", "localhost")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn readyz_200(pool: DbPool) {
+        let server = MockServer::new_async().await;
+        let path = "/api/v1/date.json";
+        let json_body = json!({ "2025-01-01": "休み" });
+        {
+            let mut srv = server.0.lock().await;
+            srv.mock("GET", path)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(json_body.to_string())
+                .create_async()
+                .await;
+            let (addr, client) = client(pool).await;
+
+            let response = client
+                .request(
+                    Request::builder()
+                        .uri(format!("http://{addr}/readyz"))
+                        .header("Host", "localhost")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(
+                body,
+                json!({
+                    "status": "ok",
+                    "checks": { "database": "ok", "holidays": "ok" },
+                })
+            );
+            srv.reset();
+        }
+    }
+
+    async fn request_readyz(client: &Client<HttpConnector, Body>, addr: SocketAddr) -> Value {
+        let response = client
+            .request(
+                Request::builder()
+                    .uri(format!("http://{addr}/readyz"))
+                    .header("Host", "localhost")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn readyz_200_続けて呼んだ場合_取得元を確かめた結果を使い回すこと(
+        pool: DbPool,
+    ) {
+        let server = MockServer::new_async().await;
+        let mut srv = server.0.lock().await;
+        let mock = srv
+            .mock("GET", "/api/v1/date.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "2025-01-01": "休み" }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let (addr, client) = client(pool).await;
+
+        request_readyz(&client, addr).await;
+        let body = request_readyz(&client, addr).await;
+
+        assert_eq!(body["checks"]["holidays"], json!("ok"));
+        mock.assert_async().await;
+        srv.reset();
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn readyz_200_取得元に繋がらない場合(pool: DbPool) {
+        let server = MockServer::new_async().await;
+        let mut srv = server.0.lock().await;
+        srv.mock("GET", "/api/v1/date.json")
+            .with_status(500)
+            .create_async()
+            .await;
+        let (addr, client) = client(pool).await;
+
+        let body = request_readyz(&client, addr).await;
+
+        // 前回取得した祝日があるかは、同じプロセスで先に動いたテストによる
+        assert!(
+            body["checks"]["holidays"] == json!("cached")
+                || body["checks"]["holidays"] == json!("computed")
+        );
+        srv.reset();
+    }
+}
@@ -0,0 +1,44 @@
+use axum::http::HeaderMap;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// `Idempotency-Key` ヘッダーで指定された冪等キー
+#[derive(Debug, Clone)]
+pub struct IdempotencyKeyHeader(pub String);
+
+impl IdempotencyKeyHeader {
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get(IDEMPOTENCY_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| Self(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn from_headers_ヘッダーがある場合_値を返すこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(IDEMPOTENCY_KEY_HEADER, HeaderValue::from_static(" key "));
+
+        let result = IdempotencyKeyHeader::from_headers(&headers);
+
+        assert_eq!(result.unwrap().0, "key");
+    }
+
+    #[test]
+    fn from_headers_ヘッダーが空の場合_noneを返すこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(IDEMPOTENCY_KEY_HEADER, HeaderValue::from_static(""));
+
+        let result = IdempotencyKeyHeader::from_headers(&headers);
+
+        assert!(result.is_none());
+    }
+}
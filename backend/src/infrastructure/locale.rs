@@ -0,0 +1,141 @@
+use std::future::Future;
+
+use axum::http::{HeaderMap, header};
+use chrono::{Datelike, NaiveDate};
+
+/// 利用者が UI で選んだ言語。`Accept-Language` より優先する
+pub const LOCALE_HEADER: &str = "x-locale";
+
+/// エラーメッセージと日付の表記に使う言語
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    Ja,
+    En,
+}
+
+tokio::task_local! {
+    static CURRENT_LOCALE: Locale;
+}
+
+impl Locale {
+    fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next()?.trim().to_ascii_lowercase();
+        match language.as_str() {
+            "ja" => Some(Self::Ja),
+            "en" => Some(Self::En),
+            _ => None,
+        }
+    }
+
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let preference = headers
+            .get(LOCALE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(Self::from_tag);
+        if let Some(locale) = preference {
+            return locale;
+        }
+
+        headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(Self::from_accept_language)
+            .unwrap_or_default()
+    }
+
+    /// 対応している言語のうち、品質値が最も高いものを選ぶ
+    fn from_accept_language(accept_language: &str) -> Option<Self> {
+        accept_language
+            .split(',')
+            .enumerate()
+            .filter_map(|(index, range)| {
+                let mut parts = range.split(';');
+                let locale = Self::from_tag(parts.next()?)?;
+                let quality = parts
+                    .find_map(|part| part.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |quality| quality.parse::<f32>().ok())?;
+
+                (quality > 0.0).then_some((locale, quality, index))
+            })
+            // 品質値が同じなら先に書かれたほうを選ぶ
+            .max_by(|(_, a, a_index), (_, b, b_index)| a.total_cmp(b).then(b_index.cmp(a_index)))
+            .map(|(locale, _, _)| locale)
+    }
+
+    /// リクエストの処理中の言語。リクエストの外では日本語になる
+    pub fn current() -> Self {
+        CURRENT_LOCALE
+            .try_with(|locale| *locale)
+            .unwrap_or_default()
+    }
+
+    /// `future` の中では `Locale::current` がこの言語を返す
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT_LOCALE.scope(self, future).await
+    }
+
+    pub fn format_date(self, date: NaiveDate) -> String {
+        match self {
+            Self::Ja => format!("{}年{}月{}日", date.year(), date.month(), date.day()),
+            Self::En => date.format("%b %-d, %Y").to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(name: &'static str, value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_static(value));
+
+        headers
+    }
+
+    #[test]
+    fn from_headers_ヘッダーがない場合_日本語になること() {
+        assert_eq!(Locale::from_headers(&HeaderMap::new()), Locale::Ja);
+    }
+
+    #[test]
+    fn from_headers_品質値の高い言語を選ぶこと() {
+        let headers = headers("accept-language", "ja;q=0.5, en-US;q=0.9, fr");
+
+        assert_eq!(Locale::from_headers(&headers), Locale::En);
+    }
+
+    #[test]
+    fn from_headers_対応していない言語だけの場合_日本語になること() {
+        let headers = headers("accept-language", "fr-FR, de;q=0.8");
+
+        assert_eq!(Locale::from_headers(&headers), Locale::Ja);
+    }
+
+    #[test]
+    fn from_headers_利用者の設定がある場合_accept_languageより優先すること() {
+        let mut headers = headers("accept-language", "en");
+        headers.insert(LOCALE_HEADER, HeaderValue::from_static("ja"));
+
+        assert_eq!(Locale::from_headers(&headers), Locale::Ja);
+    }
+
+    #[test]
+    fn format_date() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        assert_eq!(Locale::Ja.format_date(date), "2025年3月1日");
+        assert_eq!(Locale::En.format_date(date), "Mar 1, 2025");
+    }
+
+    #[tokio::test]
+    async fn scope_中では指定した言語を返すこと() {
+        assert_eq!(
+            Locale::En.scope(async { Locale::current() }).await,
+            Locale::En
+        );
+        assert_eq!(Locale::current(), Locale::Ja);
+    }
+}
@@ -0,0 +1,12 @@
+use tracing_subscriber::EnvFilter;
+
+/// ログを JSON で標準エラー出力に書き出す。出力するレベルは `RUST_LOG` で変えられる
+pub fn init() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true)
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
@@ -0,0 +1,170 @@
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use once_cell::sync::Lazy;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+
+use crate::infrastructure::database::DbPool;
+
+pub static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
+
+pub static HTTP_REQUESTS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register(IntCounterVec::new(
+        Opts::new("http_requests_total", "HTTP リクエスト数"),
+        &["method", "path", "status"],
+    ))
+});
+
+pub static HTTP_REQUEST_DURATION_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
+    register(HistogramVec::new(
+        HistogramOpts::new("http_request_duration_seconds", "HTTP リクエストの処理時間"),
+        &["method", "path"],
+    ))
+});
+
+pub static GRAPHQL_OPERATION_DURATION_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
+    register(HistogramVec::new(
+        HistogramOpts::new(
+            "graphql_operation_duration_seconds",
+            "GraphQL のオペレーションごとの実行時間",
+        ),
+        &["operation_type", "operation_name", "succeeded"],
+    ))
+});
+
+pub static HOLIDAY_FETCH_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register(IntCounterVec::new(
+        Opts::new("holiday_fetch_total", "祝日の取得回数"),
+        &["result"],
+    ))
+});
+
+pub static HOLIDAY_FETCH_DURATION_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
+    register(HistogramVec::new(
+        HistogramOpts::new("holiday_fetch_duration_seconds", "祝日の取得にかかった時間"),
+        &["result"],
+    ))
+});
+
+pub static DB_POOL_CONNECTIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register(IntGauge::new(
+        "db_pool_connections",
+        "プールが保持している DB 接続数",
+    ))
+});
+
+pub static DB_POOL_IDLE_CONNECTIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register(IntGauge::new(
+        "db_pool_idle_connections",
+        "プールで待機している DB 接続数",
+    ))
+});
+
+fn register<T>(collector: prometheus::Result<T>) -> T
+where
+    T: prometheus::core::Collector + Clone + 'static,
+{
+    let collector = collector.expect("メトリクスの定義が不正です");
+    REGISTRY
+        .register(Box::new(collector.clone()))
+        .expect("同じ名前のメトリクスが登録されています");
+
+    collector
+}
+
+/// 祝日の取得結果を記録する
+pub fn observe_holiday_fetch(succeeded: bool, started_at: Instant) {
+    let result = if succeeded { "success" } else { "failure" };
+    HOLIDAY_FETCH_TOTAL.with_label_values(&[result]).inc();
+    HOLIDAY_FETCH_DURATION_SECONDS
+        .with_label_values(&[result])
+        .observe(started_at.elapsed().as_secs_f64());
+}
+
+/// HTTP リクエストの件数と処理時間を記録するミドルウェア
+pub async fn track_http(request: Request, next: Next) -> Response {
+    let started_at = Instant::now();
+    let method = request.method().to_string();
+    // パスごとに系列が増えないよう、ルーティングに一致したパターンで集計する
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let response = next.run(request).await;
+
+    HTTP_REQUESTS_TOTAL
+        .with_label_values(&[&method, &path, response.status().as_str()])
+        .inc();
+    HTTP_REQUEST_DURATION_SECONDS
+        .with_label_values(&[&method, &path])
+        .observe(started_at.elapsed().as_secs_f64());
+
+    response
+}
+
+pub async fn metrics(State(pool): State<DbPool>) -> impl IntoResponse {
+    DB_POOL_CONNECTIONS.set(i64::from(pool.size()));
+    DB_POOL_IDLE_CONNECTIONS.set(pool.num_idle() as i64);
+
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    if let Err(error) = encoder.encode(&REGISTRY.gather(), &mut buffer) {
+        return (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response();
+    }
+
+    (
+        [(header::CONTENT_TYPE, encoder.format_type().to_string())],
+        buffer,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http};
+    use http_body_util::BodyExt;
+
+    use crate::tests::utils::client::client;
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn metrics_200(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+        client
+            .request(
+                http::Request::builder()
+                    .uri(format!("http://{addr}/healthz"))
+                    .header("Host", "localhost")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let response = client
+            .request(
+                http::Request::builder()
+                    .uri(format!("http://{addr}/metrics"))
+                    .header("Host", "localhost")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(body.contains(r#"http_requests_total{method="GET",path="/healthz",status="200"}"#));
+        assert!(body.contains("db_pool_connections"));
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    Json,
+    extract::{ConnectInfo, OriginalUri, Request, State},
+    http::{HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+
+use crate::{
+    errors::AppError,
+    infrastructure::{actor::client_ip, config::RateLimitConfig, locale::Locale},
+};
+
+/// 保持するバケツがこれを超えたら、満タンに戻ったものを捨てる
+const MAX_BUCKETS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// 接続元の IP ごとのトークンバケツ。
+/// `X-Actor` は誰でも名乗れ、名前を変えれば上限を逃れられるので、キーにしない
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_second: f64,
+    trusted_proxies: Arc<[IpAddr]>,
+    buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig, trusted_proxies: &[IpAddr]) -> Self {
+        Self {
+            capacity: f64::from(config.burst),
+            refill_per_second: config.requests_per_second,
+            trusted_proxies: trusted_proxies.into(),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// トークンが残っていれば 1 つ消費する。
+    /// 足りなければ、次にトークンが貯まるまでの時間を返す
+    fn acquire(&self, key: IpAddr, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        if buckets.len() > MAX_BUCKETS {
+            buckets.retain(|_, bucket| self.refill(*bucket, now).tokens < self.capacity);
+        }
+
+        let bucket = buckets.get(&key).copied().unwrap_or(Bucket {
+            tokens: self.capacity,
+            updated_at: now,
+        });
+        let bucket = self.refill(bucket, now);
+        let shortage = 1.0 - bucket.tokens;
+        if shortage > 0.0 {
+            return Err(Duration::from_secs_f64(shortage / self.refill_per_second));
+        }
+
+        buckets.insert(
+            key,
+            Bucket {
+                tokens: bucket.tokens - 1.0,
+                ..bucket
+            },
+        );
+
+        Ok(())
+    }
+
+    fn refill(&self, bucket: Bucket, now: Instant) -> Bucket {
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+
+        Bucket {
+            tokens: (bucket.tokens + elapsed.as_secs_f64() * self.refill_per_second)
+                .min(self.capacity),
+            updated_at: now,
+        }
+    }
+}
+
+/// 上限を超えたリクエストを 429 で断るミドルウェア。
+/// 祝日の取得元にリクエストが流れ込まないよう、GraphQL と REST API (`/api/*`) のエンドポイントに掛け、
+/// ヘルスチェックとメトリクスには掛けない
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client_ip = client_ip(request.headers(), remote_addr, &limiter.trusted_proxies);
+
+    match limiter.acquire(client_ip, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            let retry_after_secs = retry_after.as_secs_f64().ceil() as u64;
+            let error = AppError::RateLimited { retry_after_secs };
+            // 断ったリクエストは本文を読まず DB にも書かない。大量に送られても負荷が増えないよう、ログにだけ残す
+            let path = request
+                .extensions()
+                .get::<OriginalUri>()
+                .map_or_else(|| request.uri().path(), |uri| uri.path());
+            tracing::warn!(
+                %client_ip,
+                method = %request.method(),
+                path,
+                retry_after_secs,
+                "リクエスト数の上限を超えたため断りました"
+            );
+            let locale = Locale::from_headers(request.headers());
+            let body = json!({
+                "errors": [{
+                    "message": error.message(locale),
+                    "extensions": { "code": error.code() },
+                }],
+            });
+
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, HeaderValue::from(retry_after_secs))],
+                Json(body),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use http_body_util::BodyExt;
+
+    use crate::{
+        infrastructure::{config::Config, database::DbPool},
+        tests::utils::client::client_with_config,
+    };
+
+    fn limiter(burst: u32, requests_per_second: f64) -> RateLimiter {
+        RateLimiter::new(
+            &RateLimitConfig {
+                enabled: true,
+                burst,
+                requests_per_second,
+            },
+            &[],
+        )
+    }
+
+    fn ip(ip: &str) -> IpAddr {
+        ip.parse().unwrap()
+    }
+
+    #[test]
+    fn acquire_上限までは通し_超えたら待ち時間を返すこと() {
+        let limiter = limiter(2, 1.0);
+        let key = ip("10.0.0.1");
+        let now = Instant::now();
+
+        assert!(limiter.acquire(key, now).is_ok());
+        assert!(limiter.acquire(key, now).is_ok());
+        assert_eq!(limiter.acquire(key, now), Err(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn acquire_時間が経つとトークンが戻ること() {
+        let limiter = limiter(1, 2.0);
+        let key = ip("10.0.0.1");
+        let now = Instant::now();
+
+        assert!(limiter.acquire(key, now).is_ok());
+        assert!(limiter.acquire(key, now).is_err());
+        assert!(
+            limiter
+                .acquire(key, now + Duration::from_millis(500))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn acquire_接続元ごとに数えること() {
+        let limiter = limiter(1, 1.0);
+        let now = Instant::now();
+
+        assert!(limiter.acquire(ip("10.0.0.1"), now).is_ok());
+        assert!(limiter.acquire(ip("10.0.0.2"), now).is_ok());
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn rate_limit_上限を超えた場合_429を返すこと(pool: DbPool) {
+        let mut config = Config::default();
+        config.rate_limit.burst = 1;
+        config.rate_limit.requests_per_second = 0.1;
+        let (addr, client) = client_with_config(pool, config).await;
+        let request = || {
+            axum::http::Request::builder()
+                .method("POST")
+                .uri(format!("http://{addr}/graphql"))
+                .header("Host", "localhost")
+                .header("Content-Type", "application/json")
+                .body(Body::from(json!({ "query": "{ __typename }" }).to_string()))
+                .unwrap()
+        };
+
+        let response = client.request(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = client.request(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "10");
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body["errors"][0]["extensions"]["code"],
+            json!("RATE_LIMITED")
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn rate_limit_名前や転送元を変えても_接続元が同じ場合_429を返すこと(
+        pool: DbPool,
+    ) {
+        let mut config = Config::default();
+        config.rate_limit.burst = 1;
+        config.rate_limit.requests_per_second = 0.1;
+        let (addr, client) = client_with_config(pool, config).await;
+        let request = |actor: &str, forwarded_for: &str| {
+            axum::http::Request::builder()
+                .method("POST")
+                .uri(format!("http://{addr}/graphql"))
+                .header("Host", "localhost")
+                .header("Content-Type", "application/json")
+                .header("X-Actor", actor)
+                .header("X-Forwarded-For", forwarded_for)
+                .body(Body::from(json!({ "query": "{ __typename }" }).to_string()))
+                .unwrap()
+        };
+
+        let response = client.request(request("yamada", "10.0.0.1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 信用するプロキシを設定していないので、X-Forwarded-For は無視する
+        let response = client.request(request("suzuki", "10.0.0.2")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+}
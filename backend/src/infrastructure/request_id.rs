@@ -0,0 +1,97 @@
+use axum::http::HeaderMap;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// リクエストごとに振る ID。ログとレスポンスの両方に載せて突き合わせられるようにする
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| Self(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{HeaderValue, Request, StatusCode},
+    };
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{infrastructure::database::DbPool, tests::utils::client::client};
+
+    #[test]
+    fn from_headers_ヘッダーがある場合_値を返すこと() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("request-id"));
+
+        let result = RequestId::from_headers(&headers);
+
+        assert_eq!(result.unwrap().0, "request-id");
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn graphql_エラーの場合_リクエストidを返すこと(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/graphql"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({ "query": "{ latestResignation { id } }" }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(!request_id.is_empty());
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body["errors"][0]["extensions"]["requestId"],
+            json!(request_id)
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn リクエストidを指定した場合_同じidを返すこと(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .uri(format!("http://{addr}/healthz"))
+                    .header("Host", "localhost")
+                    .header(REQUEST_ID_HEADER, "client-request-id")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.headers().get(REQUEST_ID_HEADER).unwrap(),
+            "client-request-id"
+        );
+    }
+}
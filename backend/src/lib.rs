@@ -1,8 +1,12 @@
+pub mod calendars;
+pub mod cli;
 pub mod clients;
 pub mod consts;
+pub mod errors;
 pub mod graphql;
 pub mod infrastructure;
 pub mod models;
+pub mod rest;
 #[cfg(test)]
 pub mod tests;
 pub mod utils;
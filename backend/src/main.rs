@@ -1,11 +1,105 @@
+use std::net::SocketAddr;
+
 use anyhow::Result;
-use backend::infrastructure::{app::app, database::get_pool};
-use tokio::net::TcpListener;
+use backend::{
+    cli::{migrate, schema, seed},
+    infrastructure::{
+        app::app,
+        config::{Config, ConfigArgs},
+        database::{DbPool, get_pool},
+        logging,
+    },
+    utils::time::set_offset,
+};
+use clap::{Parser, Subcommand};
+use tokio::{net::TcpListener, signal};
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(flatten)]
+    config: ConfigArgs,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// サーバーを起動する (サブコマンドを省略した場合と同じ)
+    Serve,
+    /// 組み込みのマイグレーションを適用する
+    Migrate,
+    /// デモデータ (利用者、退職届と引き継ぎタスク、休日) を投入する
+    Seed,
+    /// GraphQL スキーマを SDL で出力する
+    Schema,
+}
+
+/// 設定を読み込んで DB に接続する。SDL の出力にはどちらも要らないので、必要なときだけ呼ぶ
+async fn load(args: ConfigArgs) -> Result<(Config, DbPool)> {
+    let config = Config::load(args)?;
+    set_offset(config.offset()?);
+    let pool = get_pool(&config.database).await?;
+
+    Ok((config, pool))
+}
+
+/// SIGTERM か Ctrl+C を受け取るまで待つ
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(_) => std::future::pending().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+async fn serve(args: ConfigArgs) -> Result<()> {
+    let (config, pool) = load(args).await?;
+    let listener = TcpListener::bind(config.listen_address).await?;
+    tracing::info!(listen_address = %config.listen_address, "サーバーを起動しました");
+    // 処理中のリクエストが終わるのを待ってから DB の接続を閉じる
+    axum::serve(
+        listener,
+        app(pool.clone(), config)?.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
+    pool.close().await;
+    tracing::info!("サーバーを停止しました");
+
+    Ok(())
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let pool = get_pool().await?;
-    axum::serve(TcpListener::bind("127.0.0.1:8000").await?, app(pool)).await?;
+    dotenv::dotenv().ok();
+    logging::init();
+    let cli = Cli::parse();
 
-    Ok(())
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(cli.config).await,
+        Command::Migrate => migrate::run(&load(cli.config).await?.1).await,
+        Command::Seed => {
+            let (config, pool) = load(cli.config).await?;
+            seed::run(&pool, &config.holidays).await
+        }
+        Command::Schema => {
+            schema::run();
+            Ok(())
+        }
+    }
 }
@@ -1 +1,10 @@
+pub mod audit_log;
+pub mod business_calendar;
+pub mod handover_task;
+pub mod holiday;
+pub mod idempotency_key;
+pub mod persisted_query;
 pub mod resignation;
+pub mod resignation_revision;
+pub mod resignation_status_history;
+pub mod user;
@@ -0,0 +1,162 @@
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use sqlx::Result;
+
+use crate::{infrastructure::database::DbPool, repositories::audit_log as repository};
+
+const REDACTED: &str = "[REDACTED]";
+const SENSITIVE_KEYS: [&str; 5] = ["password", "token", "secret", "authorization", "apikey"];
+
+pub struct AuditLog {
+    pub id: i32,
+    pub operation_name: String,
+    pub variables: String,
+    /// `X-Actor` で名乗った名前。認証していないので、本人とは限らない
+    pub claimed_actor: Option<String>,
+    pub client_ip: Option<String>,
+    pub succeeded: bool,
+    pub errors: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+pub struct AuditLogInput {
+    pub operation_name: String,
+    pub variables: String,
+    /// `X-Actor` で名乗った名前。認証していないので、本人とは限らない
+    pub claimed_actor: Option<String>,
+    pub client_ip: Option<String>,
+    pub succeeded: bool,
+    pub errors: Option<String>,
+}
+
+#[derive(Default)]
+pub struct AuditLogFilter {
+    pub operation_name: Option<String>,
+    pub claimed_actor: Option<String>,
+    pub succeeded: Option<bool>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    pub limit: u32,
+}
+
+impl AuditLog {
+    pub async fn fetch_all(pool: &DbPool, filter: &AuditLogFilter) -> Result<Vec<Self>> {
+        repository::fetch_all(pool, filter).await
+    }
+
+    pub async fn insert(pool: &DbPool, input: &AuditLogInput) -> Result<()> {
+        repository::insert(pool, input).await
+    }
+
+    /// 記録できなくても操作そのものは失敗させず、ログに残すだけにする
+    pub async fn record(pool: &DbPool, input: &AuditLogInput) {
+        if let Err(error) = Self::insert(pool, input).await {
+            tracing::error!(%error, "監査ログを記録できませんでした");
+        }
+    }
+}
+
+/// 記録する変数のうち、機密情報らしいキーの値を伏せる
+pub fn redact(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let normalized = key.to_lowercase().replace(['_', '-'], "");
+                    if SENSITIVE_KEYS
+                        .iter()
+                        .any(|sensitive_key| normalized.contains(sensitive_key))
+                    {
+                        (key, Value::String(REDACTED.to_string()))
+                    } else {
+                        (key, redact(value))
+                    }
+                })
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(redact).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn input(operation_name: &str, claimed_actor: &str, succeeded: bool) -> AuditLogInput {
+        AuditLogInput {
+            operation_name: operation_name.to_string(),
+            variables: "{}".to_string(),
+            claimed_actor: Some(claimed_actor.to_string()),
+            client_ip: Some("127.0.0.1".to_string()),
+            succeeded,
+            errors: None,
+        }
+    }
+
+    #[test]
+    fn redact_機密情報のキーを伏せること() {
+        let value = json!({
+            "input": {
+                "retirementDate": "2025-01-01",
+                "apiKey": "abc",
+                "nested": [{ "password": "pass" }]
+            },
+            "accessToken": "xyz"
+        });
+
+        let result = redact(value);
+
+        assert_eq!(
+            result,
+            json!({
+                "input": {
+                    "retirementDate": "2025-01-01",
+                    "apiKey": REDACTED,
+                    "nested": [{ "password": REDACTED }]
+                },
+                "accessToken": REDACTED
+            })
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn fetch_all(pool: DbPool) {
+        AuditLog::insert(&pool, &input("postResignation", "yamada", true))
+            .await
+            .unwrap();
+        AuditLog::insert(&pool, &input("postResignation", "suzuki", false))
+            .await
+            .unwrap();
+        AuditLog::insert(&pool, &input("submitResignation", "yamada", true))
+            .await
+            .unwrap();
+
+        let filter = AuditLogFilter {
+            claimed_actor: Some("yamada".to_string()),
+            limit: 100,
+            ..Default::default()
+        };
+        let audit_logs = AuditLog::fetch_all(&pool, &filter).await.unwrap();
+        assert_eq!(audit_logs.len(), 2);
+
+        let filter = AuditLogFilter {
+            operation_name: Some("postResignation".to_string()),
+            succeeded: Some(false),
+            limit: 100,
+            ..Default::default()
+        };
+        let audit_logs = AuditLog::fetch_all(&pool, &filter).await.unwrap();
+        assert_eq!(audit_logs.len(), 1);
+        assert_eq!(audit_logs[0].claimed_actor.as_deref(), Some("suzuki"));
+
+        let filter = AuditLogFilter {
+            limit: 1,
+            ..Default::default()
+        };
+        let audit_logs = AuditLog::fetch_all(&pool, &filter).await.unwrap();
+        assert_eq!(audit_logs.len(), 1);
+    }
+}
@@ -0,0 +1,267 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::errors::AppError;
+
+/// 週末として休みにする既定の曜日
+pub const DEFAULT_WEEKEND: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
+
+/// 1 年分の休日のビット列。1 月 1 日を 0 ビット目とする
+type YearBits = [u64; 6];
+
+/// 休日と週末の曜日から営業日を判定するカレンダー
+///
+/// 有給の消化や引き継ぎの期限など、営業日を数える計算はすべてこれを通す。
+/// 休日は年ごとのビット列で持つので、営業日かどうかは日付の文字列を作らずに定数時間で判定できる。
+#[derive(Debug, Clone)]
+pub struct BusinessCalendar {
+    /// `years` の最初の年
+    first_year: i32,
+    /// `first_year` から順に、年ごとの休日
+    years: Vec<YearBits>,
+    /// 昇順に並べた休日
+    holidays: Vec<NaiveDate>,
+    /// 週末ではない曜日にある休日。営業日を数えるときに二分探索する
+    weekday_holidays: Vec<NaiveDate>,
+    /// 月曜日から順に、週末として休みにする曜日か
+    weekend: [bool; 7],
+}
+
+impl BusinessCalendar {
+    /// 土曜日と日曜日を週末とするカレンダー
+    pub fn new(holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        let mut holidays = holidays.into_iter().collect::<Vec<_>>();
+        holidays.sort_unstable();
+        holidays.dedup();
+        let first_year = holidays.first().map_or(0, |date| date.year());
+        let last_year = holidays.last().map_or(-1, |date| date.year());
+        let mut years = vec![YearBits::default(); (last_year - first_year + 1) as usize];
+        for date in &holidays {
+            let ordinal = date.ordinal0() as usize;
+            years[(date.year() - first_year) as usize][ordinal / 64] |= 1 << (ordinal % 64);
+        }
+
+        Self {
+            first_year,
+            years,
+            holidays,
+            weekday_holidays: Vec::new(),
+            weekend: [false; 7],
+        }
+        .with_weekend(&DEFAULT_WEEKEND)
+    }
+
+    /// 週末として休みにする曜日を置き換える
+    pub fn with_weekend(mut self, weekend: &[Weekday]) -> Self {
+        self.weekend = [false; 7];
+        for weekday in weekend {
+            self.weekend[weekday.num_days_from_monday() as usize] = true;
+        }
+        self.weekday_holidays = self
+            .holidays
+            .iter()
+            .copied()
+            .filter(|date| !self.is_weekend(*date))
+            .collect();
+        self
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// `date` の `days` 営業日後の日付。`date` 自体は数えない
+    pub fn add_business_days(&self, date: NaiveDate, days: u32) -> Result<NaiveDate, AppError> {
+        self.shift_business_days(date, days, |date| date.checked_add_days(Days::new(1)))
+    }
+
+    /// `date` の `days` 営業日前の日付。`date` 自体は数えない
+    pub fn sub_business_days(&self, date: NaiveDate, days: u32) -> Result<NaiveDate, AppError> {
+        self.shift_business_days(date, days, |date| date.checked_sub_days(Days::new(1)))
+    }
+
+    /// `from` から `to` まで (両端を含む) の営業日の数。`from` が `to` より後の場合は 0
+    ///
+    /// 1 日ずつ数えずに、週の数と端数の曜日から週末でない日を求め、その期間の休日を二分探索で引く。
+    pub fn business_days_between(&self, from: NaiveDate, to: NaiveDate) -> u32 {
+        if from > to {
+            return 0;
+        }
+        let days = (to - from).num_days() + 1;
+        let weekdays_per_week = self.weekend.iter().filter(|weekend| !**weekend).count() as i64;
+        let first_weekday = i64::from(from.weekday().num_days_from_monday());
+        let rest_weekdays = (0..days % 7)
+            .filter(|offset| !self.weekend[((first_weekday + offset) % 7) as usize])
+            .count() as i64;
+        let holidays = self.weekday_holidays.partition_point(|date| *date <= to)
+            - self.weekday_holidays.partition_point(|date| *date < from);
+
+        (days / 7 * weekdays_per_week + rest_weekdays - holidays as i64) as u32
+    }
+
+    /// `from` から `to` まで (両端を含む) の営業日を順に返す
+    pub fn business_days(&self, from: NaiveDate, to: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+        from.iter_days()
+            .take_while(move |date| *date <= to)
+            .filter(move |date| self.is_business_day(*date))
+    }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend[date.weekday().num_days_from_monday() as usize]
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let Some(bits) = usize::try_from(date.year() - self.first_year)
+            .ok()
+            .and_then(|index| self.years.get(index))
+        else {
+            return false;
+        };
+        let ordinal = date.ordinal0() as usize;
+
+        bits[ordinal / 64] & (1 << (ordinal % 64)) != 0
+    }
+
+    fn shift_business_days(
+        &self,
+        mut date: NaiveDate,
+        mut days: u32,
+        step: impl Fn(NaiveDate) -> Option<NaiveDate>,
+    ) -> Result<NaiveDate, AppError> {
+        // すべての曜日が週末の場合は、営業日が見つからないまま日付の範囲を使い切ってしまう
+        if days > 0 && self.weekend.iter().all(|weekend| *weekend) {
+            return Err(AppError::InvalidDate);
+        }
+        while days > 0 {
+            date = step(date).ok_or(AppError::InvalidDate)?;
+            if self.is_business_day(date) {
+                days -= 1;
+            }
+        }
+
+        Ok(date)
+    }
+}
+
+impl Default for BusinessCalendar {
+    /// 休日がなく、土曜日と日曜日だけを休みにするカレンダー
+    fn default() -> Self {
+        Self::new(Vec::<NaiveDate>::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    // 2024-12-28, 2024-12-29 は土日、2024-12-31, 2025-01-01 は休日
+    fn calendar() -> BusinessCalendar {
+        BusinessCalendar::new([date("2024-12-31"), date("2025-01-01")])
+    }
+
+    #[test]
+    fn is_business_day_週末と休日の場合_営業日ではないこと() {
+        let calendar = calendar();
+
+        assert!(calendar.is_business_day(date("2024-12-30")));
+        assert!(!calendar.is_business_day(date("2024-12-28")));
+        assert!(!calendar.is_business_day(date("2024-12-29")));
+        assert!(!calendar.is_business_day(date("2025-01-01")));
+    }
+
+    #[test]
+    fn with_weekend_週末の曜日を置き換えること() {
+        let calendar = BusinessCalendar::default().with_weekend(&[Weekday::Fri, Weekday::Sat]);
+
+        assert!(!calendar.is_business_day(date("2024-12-27")));
+        assert!(!calendar.is_business_day(date("2024-12-28")));
+        assert!(calendar.is_business_day(date("2024-12-29")));
+    }
+
+    #[test]
+    fn add_business_days_休日を飛ばして数えること() {
+        let calendar = calendar();
+
+        assert_eq!(
+            calendar.add_business_days(date("2024-12-27"), 0).unwrap(),
+            date("2024-12-27")
+        );
+        assert_eq!(
+            calendar.add_business_days(date("2024-12-27"), 2).unwrap(),
+            date("2025-01-02")
+        );
+    }
+
+    #[test]
+    fn sub_business_days_休日を飛ばして数えること() {
+        let calendar = calendar();
+
+        assert_eq!(
+            calendar.sub_business_days(date("2025-01-02"), 2).unwrap(),
+            date("2024-12-27")
+        );
+    }
+
+    #[test]
+    fn sub_business_days_すべての曜日が週末の場合_エラーになること() {
+        let calendar = BusinessCalendar::default().with_weekend(&[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+
+        assert!(calendar.sub_business_days(date("2025-01-02"), 1).is_err());
+    }
+
+    #[test]
+    fn business_days_between_両端を含めて数えること() {
+        let calendar = calendar();
+
+        assert_eq!(
+            calendar.business_days_between(date("2024-12-27"), date("2025-01-02")),
+            3
+        );
+        assert_eq!(
+            calendar.business_days_between(date("2025-01-02"), date("2024-12-27")),
+            0
+        );
+        assert_eq!(
+            calendar
+                .business_days(date("2024-12-27"), date("2025-01-02"))
+                .collect::<Vec<_>>(),
+            vec![date("2024-12-27"), date("2024-12-30"), date("2025-01-02")]
+        );
+    }
+
+    #[test]
+    fn business_days_between_1日ずつ数えた場合と一致すること() {
+        let calendar = calendar();
+        let fri_sat = calendar.clone().with_weekend(&[Weekday::Fri, Weekday::Sat]);
+        let from = date("2024-12-01");
+
+        for calendar in [&calendar, &fri_sat] {
+            for to in from.iter_days().take(60) {
+                assert_eq!(
+                    calendar.business_days_between(from, to) as usize,
+                    calendar.business_days(from, to).count()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_business_day_休日のデータがない年の場合_週末だけで判定すること() {
+        let calendar = calendar();
+
+        assert!(calendar.is_business_day(date("2030-01-01")));
+        assert!(calendar.is_business_day(date("2000-12-29")));
+        assert!(!calendar.is_business_day(date("2030-01-05")));
+    }
+}
@@ -0,0 +1,274 @@
+use anyhow::Result as AnyhowResult;
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::Result;
+use tracing::instrument;
+
+use crate::{
+    infrastructure::database::DbPool, models::holiday::HolidayMap,
+    repositories::handover_task as repository,
+};
+
+pub struct HandoverTask {
+    pub id: i32,
+    pub resignation_id: i32,
+    pub title: String,
+    pub working_days_before: u32,
+    pub completed: bool,
+    pub created_at: NaiveDateTime,
+}
+
+pub struct HandoverTaskInput {
+    pub resignation_id: i32,
+    pub title: String,
+    pub working_days_before: u32,
+}
+
+pub struct HandoverTaskUpdate {
+    pub title: Option<String>,
+    pub working_days_before: Option<u32>,
+    pub completed: Option<bool>,
+}
+
+impl HandoverTask {
+    pub async fn fetch(pool: &DbPool, id: i32) -> Result<Self> {
+        repository::fetch(pool, id).await
+    }
+
+    pub async fn fetch_by_resignation_id(pool: &DbPool, resignation_id: i32) -> Result<Vec<Self>> {
+        repository::fetch_by_resignation_id(pool, resignation_id).await
+    }
+
+    #[instrument(skip(pool, input), fields(resignation_id = input.resignation_id), err)]
+    pub async fn insert(pool: &DbPool, input: &HandoverTaskInput) -> Result<Self> {
+        repository::insert(pool, input).await
+    }
+
+    #[instrument(skip(pool, update), err)]
+    pub async fn update(pool: &DbPool, id: i32, update: &HandoverTaskUpdate) -> Result<Self> {
+        let mut handover_task = Self::fetch(pool, id).await?;
+        if let Some(title) = &update.title {
+            handover_task.title = title.clone();
+        }
+        if let Some(working_days_before) = update.working_days_before {
+            handover_task.working_days_before = working_days_before;
+        }
+        if let Some(completed) = update.completed {
+            handover_task.completed = completed;
+        }
+        repository::update(pool, &handover_task).await?;
+
+        Ok(handover_task)
+    }
+
+    #[instrument(skip(pool), err)]
+    pub async fn delete(pool: &DbPool, id: i32) -> Result<Self> {
+        let handover_task = Self::fetch(pool, id).await?;
+        repository::delete(pool, id).await?;
+
+        Ok(handover_task)
+    }
+
+    pub async fn completion_percentage(pool: &DbPool, resignation_id: i32) -> Result<f64> {
+        let handover_tasks = Self::fetch_by_resignation_id(pool, resignation_id).await?;
+
+        Ok(completion_percentage(&handover_tasks))
+    }
+
+    pub fn due_date(
+        &self,
+        vacation_start_date: NaiveDate,
+        holidays: &HolidayMap,
+    ) -> AnyhowResult<NaiveDate> {
+        due_date(vacation_start_date, self.working_days_before, holidays)
+    }
+}
+
+/// 休暇開始日から `working_days_before` 営業日前の日付を期限とする
+///
+/// 期限までの期間に祝日のデータがない年が含まれる場合はエラーにする。
+/// 保存する前に期限を計算できるか確かめられるよう、保存したタスクがなくても呼べるようにしている。
+pub fn due_date(
+    vacation_start_date: NaiveDate,
+    working_days_before: u32,
+    holidays: &HolidayMap,
+) -> AnyhowResult<NaiveDate> {
+    let due_date = holidays
+        .calendar()
+        .sub_business_days(vacation_start_date, working_days_before)?;
+    holidays.ensure_covered(due_date, vacation_start_date)?;
+
+    Ok(due_date)
+}
+
+fn completion_percentage(handover_tasks: &[HandoverTask]) -> f64 {
+    if handover_tasks.is_empty() {
+        return 0.0;
+    }
+    let completed = handover_tasks.iter().filter(|task| task.completed).count();
+
+    completed as f64 / handover_tasks.len() as f64 * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
+    use super::*;
+    use crate::errors::AppError;
+
+    fn handover_task(working_days_before: u32, completed: bool) -> HandoverTask {
+        HandoverTask {
+            id: 1,
+            resignation_id: 1,
+            title: "引き継ぎ資料".to_string(),
+            working_days_before,
+            completed,
+            created_at: NaiveDateTime::parse_from_str("2025-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")
+                .unwrap(),
+        }
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures(
+            "../fixtures/resignation/resignations.sql",
+            "../fixtures/handover_task/handover_tasks.sql"
+        )
+    )]
+    async fn fetch_by_resignation_id(pool: DbPool) {
+        let handover_tasks = HandoverTask::fetch_by_resignation_id(&pool, 2222)
+            .await
+            .unwrap();
+
+        assert_eq!(handover_tasks.len(), 2);
+        assert_eq!(handover_tasks[0].id, 1);
+        assert_eq!(handover_tasks[0].working_days_before, 5);
+        assert!(handover_tasks[0].completed);
+        assert_eq!(handover_tasks[1].id, 2);
+        assert!(!handover_tasks[1].completed);
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn insert(pool: DbPool) {
+        let input = HandoverTaskInput {
+            resignation_id: 1111,
+            title: "アカウント移管".to_string(),
+            working_days_before: 3,
+        };
+
+        let handover_task = HandoverTask::insert(&pool, &input).await.unwrap();
+
+        assert_eq!(handover_task.resignation_id, 1111);
+        assert_eq!(handover_task.title, "アカウント移管");
+        assert_eq!(handover_task.working_days_before, 3);
+        assert!(!handover_task.completed);
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures(
+            "../fixtures/resignation/resignations.sql",
+            "../fixtures/handover_task/handover_tasks.sql"
+        )
+    )]
+    async fn update(pool: DbPool) {
+        let update = HandoverTaskUpdate {
+            title: None,
+            working_days_before: None,
+            completed: Some(true),
+        };
+
+        let handover_task = HandoverTask::update(&pool, 2, &update).await.unwrap();
+
+        assert_eq!(handover_task.title, "取引先への挨拶");
+        assert!(handover_task.completed);
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures(
+            "../fixtures/resignation/resignations.sql",
+            "../fixtures/handover_task/handover_tasks.sql"
+        )
+    )]
+    async fn delete(pool: DbPool) {
+        HandoverTask::delete(&pool, 1).await.unwrap();
+
+        let handover_tasks = HandoverTask::fetch_by_resignation_id(&pool, 2222)
+            .await
+            .unwrap();
+        assert_eq!(handover_tasks.len(), 1);
+    }
+
+    #[test]
+    fn due_date() {
+        let handover_task = handover_task(3, false);
+        // 土曜日: 2024-12-14
+        // 日曜日: 2024-12-15
+        let holidays = HolidayMap::new(
+            HashMap::from([(
+                NaiveDate::from_ymd_opt(2024, 12, 13).unwrap(),
+                "休み".to_string(),
+            )]),
+            BTreeSet::from([2024]),
+        );
+
+        let result =
+            handover_task.due_date(NaiveDate::from_ymd_opt(2024, 12, 17).unwrap(), &holidays);
+
+        assert_eq!(
+            result.unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 11).unwrap()
+        );
+    }
+
+    #[test]
+    fn due_date_0営業日前の場合_休暇開始日になること() {
+        let handover_task = handover_task(0, false);
+
+        let result = handover_task.due_date(
+            NaiveDate::from_ymd_opt(2024, 12, 17).unwrap(),
+            &HolidayMap::new(HashMap::new(), BTreeSet::from([2024])),
+        );
+
+        assert_eq!(
+            result.unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 17).unwrap()
+        );
+    }
+
+    #[test]
+    fn due_date_祝日のデータがない年にかかる場合_エラーになること() {
+        let handover_task = handover_task(3, false);
+
+        let result = handover_task.due_date(
+            NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+            &HolidayMap::new(HashMap::new(), BTreeSet::from([2025])),
+        );
+
+        assert_eq!(
+            AppError::from_anyhow(&result.unwrap_err()).code(),
+            "HOLIDAYS_NOT_COVERED"
+        );
+    }
+
+    #[test]
+    fn completion_percentage_完了済みの割合を返すこと() {
+        let handover_tasks = vec![
+            handover_task(1, true),
+            handover_task(2, false),
+            handover_task(3, true),
+            handover_task(4, false),
+        ];
+
+        assert_eq!(completion_percentage(&handover_tasks), 50.0);
+    }
+
+    #[test]
+    fn completion_percentage_タスクがない場合_0を返すこと() {
+        assert_eq!(completion_percentage(&[]), 0.0);
+    }
+}
@@ -0,0 +1,440 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    str::FromStr,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use anyhow::anyhow;
+use chrono::{Datelike, NaiveDate};
+use once_cell::sync::Lazy;
+use reqwest::Result;
+
+use crate::{
+    calendars::{self, Calendar, japan},
+    clients::holidays::{cached_holidays, fetch_holidays},
+    errors::AppError,
+    infrastructure::{
+        config::{CompanyHoliday, HolidayProvider, HolidaysConfig},
+        database::DbPool,
+    },
+    models::business_calendar::BusinessCalendar,
+    repositories::holiday as repository,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(rename_all = "snake_case")]
+pub enum HolidaySource {
+    /// 国民の祝日 (日本以外のカレンダーでは、その国・地域の祝日)
+    National,
+    /// 設定ファイルで指定した会社独自の休日
+    Company,
+}
+
+impl HolidaySource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::National => "national",
+            Self::Company => "company",
+        }
+    }
+}
+
+impl FromStr for HolidaySource {
+    type Err = anyhow::Error;
+
+    fn from_str(source: &str) -> anyhow::Result<Self> {
+        match source {
+            "national" => Ok(Self::National),
+            "company" => Ok(Self::Company),
+            _ => Err(anyhow!("unknown holiday source: {source}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    pub date: NaiveDate,
+    pub name: String,
+    pub source: HolidaySource,
+}
+
+/// 休日の計算に使う営業日のカレンダーと、休日の名前、祝日のデータがそろっている年
+#[derive(Debug, Clone, Default)]
+pub struct HolidayMap {
+    calendar: BusinessCalendar,
+    names: HashMap<NaiveDate, String>,
+    covered_years: BTreeSet<i32>,
+}
+
+impl HolidayMap {
+    pub fn new(
+        names: impl IntoIterator<Item = (NaiveDate, String)>,
+        covered_years: BTreeSet<i32>,
+    ) -> Self {
+        let names = names.into_iter().collect::<HashMap<_, _>>();
+        Self {
+            calendar: BusinessCalendar::new(names.keys().copied()),
+            names,
+            covered_years,
+        }
+    }
+
+    /// 休日の計算に使う営業日のカレンダー
+    pub fn calendar(&self) -> &BusinessCalendar {
+        &self.calendar
+    }
+
+    pub fn name(&self, date: NaiveDate) -> Option<&str> {
+        self.names.get(&date).map(String::as_str)
+    }
+
+    /// `from` から `to` までのすべての年の祝日のデータがあるか確かめる
+    ///
+    /// データのない年は祝日がないものとして計算してしまうため、黙って結果を返さずにエラーにする。
+    pub fn ensure_covered(&self, from: NaiveDate, to: NaiveDate) -> anyhow::Result<()> {
+        if (from.year()..=to.year()).all(|year| self.covered_years.contains(&year)) {
+            return Ok(());
+        }
+
+        Err(AppError::HolidaysNotCovered { from, to }.into())
+    }
+}
+
+/// カレンダーごとに組み立てた `HolidayMap`
+///
+/// 取得元の祝日 (`CACHED_HOLIDAYS`) が置き換わるか会社の休日が変わるまで、作り直さずに使い回す。
+static HOLIDAY_MAPS: Lazy<RwLock<HashMap<Calendar, CachedHolidayMap>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+struct CachedHolidayMap {
+    /// 作ったときに使った API の祝日。規則から計算した場合は None
+    fetched: Option<Arc<HashMap<NaiveDate, String>>>,
+    company: Vec<CompanyHoliday>,
+    holidays: Arc<HolidayMap>,
+}
+
+impl CachedHolidayMap {
+    fn is_built_from(
+        &self,
+        fetched: Option<&Arc<HashMap<NaiveDate, String>>>,
+        company: &[CompanyHoliday],
+    ) -> bool {
+        let same_fetched = match (&self.fetched, fetched) {
+            (Some(cached), Some(fetched)) => Arc::ptr_eq(cached, fetched),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_fetched && self.company == company
+    }
+}
+
+impl Holiday {
+    /// `calendar` の祝日に会社の休日を加え、日付の昇順に並べる。同じ日は祝日を優先する
+    ///
+    /// 日本以外のカレンダーは、同梱した規則から計算する。
+    pub async fn fetch_all(config: &HolidaysConfig, calendar: Calendar) -> Result<Vec<Self>> {
+        let fetched = Self::fetch_api(config, calendar).await;
+        let (national, _) = national_holidays(calendar, fetched.as_deref());
+
+        Ok(Self::merge(national, &config.company))
+    }
+
+    /// `from` から `to` まで (両端を含む) の祝日
+    pub async fn fetch_between(
+        config: &HolidaysConfig,
+        calendar: Calendar,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<Self>> {
+        let holidays = Self::fetch_all(config, calendar)
+            .await?
+            .into_iter()
+            .filter(|holiday| (from..=to).contains(&holiday.date))
+            .collect();
+
+        Ok(holidays)
+    }
+
+    /// 休日の計算に使う、祝日と会社の休日を入れた営業日のカレンダーと休日の名前
+    ///
+    /// 同じ祝日と会社の休日から作ったものがあれば、それを返す。
+    pub async fn fetch_map(config: &HolidaysConfig, calendar: Calendar) -> Result<Arc<HolidayMap>> {
+        let fetched = Self::fetch_api(config, calendar).await;
+        let cached = HOLIDAY_MAPS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&calendar)
+            .filter(|cached| cached.is_built_from(fetched.as_ref(), &config.company))
+            .map(|cached| cached.holidays.clone());
+        if let Some(holidays) = cached {
+            return Ok(holidays);
+        }
+
+        let (national, covered_years) = national_holidays(calendar, fetched.as_deref());
+        let names = Self::merge(national, &config.company)
+            .into_iter()
+            .map(|holiday| (holiday.date, holiday.name));
+        let holidays = Arc::new(HolidayMap::new(names, covered_years));
+        HOLIDAY_MAPS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                calendar,
+                CachedHolidayMap {
+                    fetched,
+                    company: config.company.clone(),
+                    holidays: holidays.clone(),
+                },
+            );
+
+        Ok(holidays)
+    }
+
+    /// `seed` などで DB に保存した `calendar` の休日を、日付の昇順に返す
+    pub async fn fetch_stored(pool: &DbPool, calendar: Calendar) -> sqlx::Result<Vec<Self>> {
+        repository::fetch_all(pool, calendar).await
+    }
+
+    /// 休日を DB に保存する。同じ日の休日が既にある場合は置き換える
+    ///
+    /// 保存した休日は計算には使わない。取得元に繋がらない環境でも、DB を見れば休日を確かめられるようにする。
+    pub async fn store(pool: &DbPool, calendar: Calendar, holidays: &[Self]) -> sqlx::Result<()> {
+        for holiday in holidays {
+            repository::upsert(pool, calendar, holiday).await?;
+        }
+
+        Ok(())
+    }
+
+    /// API から祝日を取得するカレンダーなら、API の祝日。規則から計算するカレンダーなら None
+    async fn fetch_api(
+        config: &HolidaysConfig,
+        calendar: Calendar,
+    ) -> Option<Arc<HashMap<NaiveDate, String>>> {
+        if calendar != Calendar::Jp || config.provider != HolidayProvider::Api {
+            return None;
+        }
+
+        match fetch_holidays(&config.base_url).await {
+            Ok(national) => Some(national),
+            // 取得元が落ちていても計算を止めないよう、前回取得した祝日を使い、
+            // それもなければ祝日法の規則から計算する
+            Err(error) => {
+                tracing::warn!(
+                    %error,
+                    "祝日を取得できないため、前回取得した祝日か規則から計算した祝日を使います"
+                );
+                cached_holidays()
+            }
+        }
+    }
+
+    fn merge(national: BTreeMap<NaiveDate, String>, company: &[CompanyHoliday]) -> Vec<Self> {
+        let mut holidays = national
+            .into_iter()
+            .map(|(date, name)| (date, (name, HolidaySource::National)))
+            .collect::<BTreeMap<_, _>>();
+        for company_holiday in company {
+            holidays
+                .entry(company_holiday.date)
+                .or_insert_with(|| (company_holiday.name.clone(), HolidaySource::Company));
+        }
+
+        holidays
+            .into_iter()
+            .map(|(date, (name, source))| Self { date, name, source })
+            .collect()
+    }
+}
+
+/// `calendar` の祝日と、祝日のデータがそろっている年
+///
+/// `fetched` は API から取得した祝日で、None の場合は規則から計算する。
+fn national_holidays(
+    calendar: Calendar,
+    fetched: Option<&HashMap<NaiveDate, String>>,
+) -> (BTreeMap<NaiveDate, String>, BTreeSet<i32>) {
+    let mut covered_years = calendars::SUPPORTED_YEARS.collect::<BTreeSet<_>>();
+    let national = match fetched {
+        Some(fetched) => {
+            covered_years.extend(years(fetched));
+            fill_missing_years(fetched)
+        }
+        None => calendar.holidays_in(calendars::SUPPORTED_YEARS),
+    };
+
+    (national, covered_years)
+}
+
+/// API のデータにない年の祝日を、祝日法の規則から計算して補う
+///
+/// API は今年の前後数年分しか返さないため、それより先の退職日でも祝日を考慮できるようにする。
+fn fill_missing_years(fetched: &HashMap<NaiveDate, String>) -> BTreeMap<NaiveDate, String> {
+    let covered_years = years(fetched);
+    let mut national = fetched
+        .iter()
+        .map(|(date, name)| (*date, name.clone()))
+        .collect::<BTreeMap<_, _>>();
+    for year in japan::SUPPORTED_YEARS.filter(|year| !covered_years.contains(year)) {
+        national.extend(japan::holidays(year));
+    }
+
+    national
+}
+
+/// 祝日のある年
+fn years(holidays: &HashMap<NaiveDate, String>) -> BTreeSet<i32> {
+    holidays.keys().map(|date| date.year()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mocks::server::MockServer;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn merge_会社の休日を加えて日付順に並べること() {
+        let national = BTreeMap::from([
+            (date("2025-01-13"), "成人の日".to_string()),
+            (date("2025-01-01"), "元日".to_string()),
+        ]);
+        let company = vec![CompanyHoliday {
+            date: date("2025-01-03"),
+            name: "年始休暇".to_string(),
+        }];
+
+        let holidays = Holiday::merge(national, &company);
+
+        assert_eq!(
+            holidays,
+            vec![
+                Holiday {
+                    date: date("2025-01-01"),
+                    name: "元日".to_string(),
+                    source: HolidaySource::National,
+                },
+                Holiday {
+                    date: date("2025-01-03"),
+                    name: "年始休暇".to_string(),
+                    source: HolidaySource::Company,
+                },
+                Holiday {
+                    date: date("2025-01-13"),
+                    name: "成人の日".to_string(),
+                    source: HolidaySource::National,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_同じ日の場合_国民の祝日を優先すること() {
+        let national = BTreeMap::from([(date("2025-01-01"), "元日".to_string())]);
+        let company = vec![CompanyHoliday {
+            date: date("2025-01-01"),
+            name: "年始休暇".to_string(),
+        }];
+
+        let holidays = Holiday::merge(national, &company);
+
+        assert_eq!(holidays.len(), 1);
+        assert_eq!(holidays[0].source, HolidaySource::National);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_日本以外のカレンダーの場合_同梱した規則から計算すること() {
+        let config = HolidaysConfig::default();
+
+        let holidays = Holiday::fetch_between(
+            &config,
+            Calendar::DeBy,
+            date("2025-06-01"),
+            date("2025-06-30"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            holidays
+                .iter()
+                .map(|holiday| holiday.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Pfingstmontag", "Fronleichnam"]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_map_取得元に繋がらない場合_規則から計算した祝日で補うこと() {
+        let server = MockServer::new_async().await;
+        let mut srv = server.0.lock().await;
+        srv.mock("GET", "/api/v1/date.json")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let holidays = Holiday::fetch_map(&HolidaysConfig::default(), Calendar::Jp).await;
+
+        let holidays = holidays.unwrap();
+        assert_eq!(holidays.name(date("2030-01-01")), Some("元日"));
+        assert!(
+            holidays
+                .ensure_covered(date("2030-01-01"), date("2030-12-31"))
+                .is_ok()
+        );
+        srv.reset();
+    }
+
+    #[tokio::test]
+    async fn fetch_map_同じ設定の場合_作ったものを使い回すこと() {
+        let mut config = HolidaysConfig::default();
+        config.company = vec![CompanyHoliday {
+            date: date("2025-06-02"),
+            name: "創立記念日".to_string(),
+        }];
+
+        let first = Holiday::fetch_map(&config, Calendar::De).await.unwrap();
+        let second = Holiday::fetch_map(&config, Calendar::De).await.unwrap();
+        config.company[0].name = "夏季休暇".to_string();
+        let third = Holiday::fetch_map(&config, Calendar::De).await.unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(second.name(date("2025-06-02")), Some("創立記念日"));
+        assert!(!Arc::ptr_eq(&second, &third));
+        assert_eq!(third.name(date("2025-06-02")), Some("夏季休暇"));
+    }
+
+    #[test]
+    fn ensure_covered_データのない年を含む場合_エラーになること() {
+        let holidays = HolidayMap::new(HashMap::new(), BTreeSet::from([2025, 2026]));
+
+        assert!(
+            holidays
+                .ensure_covered(date("2025-12-01"), date("2026-01-31"))
+                .is_ok()
+        );
+        let error = holidays
+            .ensure_covered(date("2026-12-01"), date("2027-01-31"))
+            .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<AppError>().unwrap().code(),
+            "HOLIDAYS_NOT_COVERED"
+        );
+    }
+
+    #[test]
+    fn fill_missing_years_apiにない年だけを計算で補うこと() {
+        let national = HashMap::from([(date("2025-01-01"), "休み".to_string())]);
+
+        let holidays = fill_missing_years(&national);
+
+        assert_eq!(holidays[&date("2025-01-01")], "休み");
+        assert!(!holidays.contains_key(&date("2025-01-13")));
+        assert_eq!(holidays[&date("2030-01-14")], "成人の日");
+        assert_eq!(holidays[&date("2024-01-08")], "成人の日");
+    }
+}
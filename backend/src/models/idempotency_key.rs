@@ -0,0 +1,118 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::Result;
+
+use crate::{
+    calendars::Calendar,
+    infrastructure::database::{DbConnection, DbPool},
+    models::resignation::{Resignation, ResignationStatus},
+    repositories::idempotency_key as repository,
+};
+
+/// 冪等キーと、そのキーで登録した退職の登録時の内容
+pub struct IdempotencyKey {
+    pub idempotency_key: String,
+    pub request: String,
+    pub resignation_id: i32,
+    pub retirement_date: NaiveDate,
+    pub remaining_paid_leave_days: u32,
+    pub calendar: Calendar,
+    pub status: ResignationStatus,
+    pub resignation_created_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+pub struct IdempotencyKeyInput {
+    pub idempotency_key: String,
+    pub request: String,
+    pub resignation_id: i32,
+    pub retirement_date: NaiveDate,
+    pub remaining_paid_leave_days: u32,
+    pub calendar: Calendar,
+    pub status: ResignationStatus,
+    pub resignation_created_at: NaiveDateTime,
+}
+
+impl IdempotencyKeyInput {
+    pub fn new(idempotency_key: &str, request: &str, resignation: &Resignation) -> Self {
+        Self {
+            idempotency_key: idempotency_key.to_string(),
+            request: request.to_string(),
+            resignation_id: resignation.id,
+            retirement_date: resignation.retirement_date,
+            remaining_paid_leave_days: resignation.remaining_paid_leave_days,
+            calendar: resignation.calendar,
+            status: resignation.status,
+            resignation_created_at: resignation.created_at,
+        }
+    }
+}
+
+impl IdempotencyKey {
+    pub async fn fetch(pool: &DbPool, idempotency_key: &str) -> Result<Option<Self>> {
+        repository::fetch(pool, idempotency_key).await
+    }
+
+    pub async fn insert(conn: &mut DbConnection, input: &IdempotencyKeyInput) -> Result<()> {
+        repository::insert(conn, input).await
+    }
+
+    pub async fn delete_expired(pool: &DbPool) -> Result<()> {
+        repository::delete_expired(pool).await
+    }
+
+    /// 登録したときの退職。登録後に更新されていても、その内容は含まない
+    pub fn resignation(&self) -> Resignation {
+        Resignation {
+            id: self.resignation_id,
+            retirement_date: self.retirement_date,
+            remaining_paid_leave_days: self.remaining_paid_leave_days,
+            calendar: self.calendar,
+            status: self.status,
+            created_at: self.resignation_created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn delete_expired(pool: DbPool) {
+        sqlx::query(
+            r#"
+            INSERT INTO
+                idempotency_key (idempotency_key, request, resignation_id, created_at, expires_at)
+            VALUES
+                ('expired', '2025-01-01:5', 2222, '2025-01-01 00:00:00', '2025-01-02 00:00:00'),
+                ('active', '2025-01-01:5', 2222, '2025-01-01 00:00:00', '9999-01-01 00:00:00')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert!(
+            IdempotencyKey::fetch(&pool, "expired")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        IdempotencyKey::delete_expired(&pool).await.unwrap();
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM idempotency_key")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+        let idempotency_key = IdempotencyKey::fetch(&pool, "active")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(idempotency_key.resignation_id, 2222);
+    }
+}
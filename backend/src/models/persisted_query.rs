@@ -0,0 +1,60 @@
+use chrono::NaiveDateTime;
+use sqlx::Result;
+
+use crate::{infrastructure::database::DbPool, repositories::persisted_query as repository};
+
+/// 自動永続化クエリ (APQ) で登録されたクエリ
+pub struct PersistedQuery {
+    pub sha256_hash: String,
+    pub query: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl PersistedQuery {
+    pub async fn fetch(pool: &DbPool, sha256_hash: &str) -> Result<Option<Self>> {
+        repository::fetch(pool, sha256_hash).await
+    }
+
+    /// 同じハッシュが登録済みの場合は何もしない
+    pub async fn insert(pool: &DbPool, sha256_hash: &str, query: &str) -> Result<()> {
+        repository::insert(pool, sha256_hash, query).await
+    }
+
+    pub async fn count(pool: &DbPool) -> Result<i64> {
+        repository::count(pool).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert_同じハッシュを登録した場合_最初のクエリを残すこと(
+        pool: DbPool,
+    ) {
+        PersistedQuery::insert(&pool, "hash", "{ a }")
+            .await
+            .unwrap();
+        PersistedQuery::insert(&pool, "hash", "{ b }")
+            .await
+            .unwrap();
+
+        let persisted_query = PersistedQuery::fetch(&pool, "hash").await.unwrap().unwrap();
+        assert_eq!(persisted_query.query, "{ a }");
+        assert!(
+            PersistedQuery::fetch(&pool, "unknown")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn count(pool: DbPool) {
+        PersistedQuery::insert(&pool, "a", "{ a }").await.unwrap();
+        PersistedQuery::insert(&pool, "b", "{ b }").await.unwrap();
+
+        assert_eq!(PersistedQuery::count(&pool).await.unwrap(), 2);
+    }
+}
@@ -1,134 +1,420 @@
-use std::collections::HashMap;
+use std::str::FromStr;
 
 use anyhow::{Context, Result as AnyhowResult, anyhow};
-use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, Weekday};
-use sqlx::{MySql, Pool, Result};
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::Result;
+use tracing::instrument;
+
+use crate::{
+    calendars::Calendar,
+    consts::paid_leave::MAX_VACATION_SEARCH_DAYS,
+    errors::AppError,
+    infrastructure::{
+        config::{PaidLeaveConfig, RetirementDatePolicy},
+        database::{DbConnection, DbPool},
+    },
+    models::{
+        business_calendar::BusinessCalendar,
+        holiday::HolidayMap,
+        idempotency_key::{IdempotencyKey, IdempotencyKeyInput},
+        resignation_revision::{ResignationRevision, ResignationRevisionInput},
+        resignation_status_history::{ResignationStatusHistory, ResignationStatusHistoryInput},
+    },
+    repositories::resignation as repository,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(rename_all = "snake_case")]
+pub enum ResignationStatus {
+    Draft,
+    Submitted,
+    Approved,
+    Rejected,
+    Withdrawn,
+    Completed,
+}
+
+impl ResignationStatus {
+    pub fn can_transition_to(self, to: ResignationStatus) -> bool {
+        use ResignationStatus::*;
+
+        matches!(
+            (self, to),
+            (Draft, Submitted)
+                | (Draft, Withdrawn)
+                | (Submitted, Approved)
+                | (Submitted, Rejected)
+                | (Submitted, Withdrawn)
+                | (Rejected, Draft)
+                | (Rejected, Withdrawn)
+                | (Approved, Withdrawn)
+                | (Approved, Completed)
+        )
+    }
 
-use crate::utils::time::now;
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Submitted => "submitted",
+            Self::Approved => "approved",
+            Self::Rejected => "rejected",
+            Self::Withdrawn => "withdrawn",
+            Self::Completed => "completed",
+        }
+    }
+}
+
+impl FromStr for ResignationStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(status: &str) -> AnyhowResult<Self> {
+        match status {
+            "draft" => Ok(Self::Draft),
+            "submitted" => Ok(Self::Submitted),
+            "approved" => Ok(Self::Approved),
+            "rejected" => Ok(Self::Rejected),
+            "withdrawn" => Ok(Self::Withdrawn),
+            "completed" => Ok(Self::Completed),
+            _ => Err(anyhow!("unknown resignation status: {status}")),
+        }
+    }
+}
 
 pub struct Resignation {
     pub id: i32,
     pub retirement_date: NaiveDate,
     pub remaining_paid_leave_days: u32,
+    /// 有給の計算に使う休日のカレンダー
+    pub calendar: Calendar,
+    pub status: ResignationStatus,
     pub created_at: NaiveDateTime,
 }
 
 pub struct ResignationInput {
     pub retirement_date: NaiveDate,
     pub remaining_paid_leave_days: u32,
+    pub calendar: Calendar,
 }
 
-impl Resignation {
-    pub async fn fetch_latest(pool: &Pool<MySql>) -> Result<Self> {
-        let latest_resignation = sqlx::query_as!(
-            Self,
-            r#"
-                SELECT
-                    id, retirement_date, remaining_paid_leave_days, created_at
-                FROM
-                    resignation
-                ORDER BY
-                    created_at DESC
-                LIMIT 1
-            "#
-        )
-        .fetch_one(pool)
-        .await?;
-
-        Ok(latest_resignation)
-    }
-
-    pub async fn insert(pool: &Pool<MySql>, input: &ResignationInput) -> Result<Resignation> {
-        let now = now();
-        let id = sqlx::query!(
-            r#"
-            INSERT INTO
-                resignation (retirement_date, remaining_paid_leave_days, created_at)
-            VALUES
-                (?, ?, ?)
-            "#,
-            input.retirement_date.to_string(),
-            input.remaining_paid_leave_days,
-            now.format("%Y-%m-%d %H:%M:%S").to_string()
-        )
-        .execute(pool)
-        .await?
-        .last_insert_id();
-
-        let resignation = sqlx::query_as!(
-            Self,
-            r#"
-            SELECT
-                id, retirement_date, remaining_paid_leave_days, created_at
-            FROM
-                resignation
-            WHERE
-                ID = ?
-        "#,
-            id
-        )
-        .fetch_one(pool)
-        .await?;
+/// 退職日が休日だったためにずらした結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetirementDateAdjustment {
+    pub policy: RetirementDatePolicy,
+    /// 入力された退職日
+    pub requested_date: NaiveDate,
+    /// ずらした後の退職日
+    pub adjusted_date: NaiveDate,
+}
 
-        Ok(resignation)
+impl ResignationInput {
+    /// 退職日が `today` より後で、残りの有給日数が上限を超えていないか確かめる
+    ///
+    /// 登録、更新、リビジョンへの差し戻しのどこから保存する場合も、保存する前にこれを通す。
+    pub fn validate(
+        &self,
+        config: &PaidLeaveConfig,
+        today: NaiveDate,
+    ) -> std::result::Result<(), AppError> {
+        if self.retirement_date <= today {
+            return Err(AppError::FutureDateRequired {
+                actual: self.retirement_date,
+            });
+        }
+        if self.remaining_paid_leave_days > config.max_remaining_days {
+            return Err(AppError::PaidLeaveDaysOutOfRange {
+                max: config.max_remaining_days,
+            });
+        }
+
+        Ok(())
     }
 
-    pub async fn vacation_start_date(
-        &self,
-        holidays: &HashMap<String, String>,
-    ) -> AnyhowResult<NaiveDate> {
-        let mut vacation_start_date = self.retirement_date;
-        let mut remaining_paid_leave_days = self.remaining_paid_leave_days;
+    /// 退職日が土日や休日の場合に、設定に従って断るか営業日にずらす。ずらした場合はその内容を返す
+    ///
+    /// 直前の営業日にずらした結果が `today` 以前になる場合は、未来の日付ではないとしてエラーにする。
+    pub fn apply_retirement_date_policy(
+        &mut self,
+        policy: RetirementDatePolicy,
+        holidays: Option<&HolidayMap>,
+        today: NaiveDate,
+    ) -> AnyhowResult<Option<RetirementDateAdjustment>> {
+        if policy == RetirementDatePolicy::Allow {
+            return Ok(None);
+        }
+        let holidays = holidays.ok_or(AppError::HolidaySourceUnavailable)?;
+        let requested_date = self.retirement_date;
+        holidays.ensure_covered(requested_date, requested_date)?;
+        if holidays.calendar().is_business_day(requested_date) {
+            return Ok(None);
+        }
+        if policy == RetirementDatePolicy::Reject {
+            return Err(AppError::NonWorkingRetirementDate {
+                date: requested_date,
+            }
+            .into());
+        }
 
-        if remaining_paid_leave_days == 0 {
-            return Err(anyhow!("有給がありません"));
+        let adjusted_date = if policy == RetirementDatePolicy::PreviousBusinessDay {
+            let adjusted_date = holidays.calendar().sub_business_days(requested_date, 1)?;
+            holidays.ensure_covered(adjusted_date, requested_date)?;
+            adjusted_date
+        } else {
+            let adjusted_date = holidays.calendar().add_business_days(requested_date, 1)?;
+            holidays.ensure_covered(requested_date, adjusted_date)?;
+            adjusted_date
+        };
+        if adjusted_date <= today {
+            return Err(AppError::FutureDateRequired {
+                actual: adjusted_date,
+            }
+            .into());
         }
+        self.retirement_date = adjusted_date;
+
+        Ok(Some(RetirementDateAdjustment {
+            policy,
+            requested_date,
+            adjusted_date,
+        }))
+    }
+
+    fn fingerprint(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.retirement_date,
+            self.remaining_paid_leave_days,
+            self.calendar.code()
+        )
+    }
+}
+
+impl Resignation {
+    #[instrument(skip_all, err)]
+    pub async fn fetch_latest(pool: &DbPool) -> Result<Self> {
+        repository::fetch_latest(pool).await
+    }
+
+    pub async fn fetch_all(pool: &DbPool) -> Result<Vec<Self>> {
+        repository::fetch_all(pool).await
+    }
+
+    #[instrument(skip(pool), err)]
+    pub async fn fetch(pool: &DbPool, id: i32) -> Result<Self> {
+        repository::fetch(pool, id).await
+    }
+
+    #[instrument(skip_all, fields(retirement_date = %input.retirement_date), err)]
+    pub async fn insert(
+        pool: &DbPool,
+        input: &ResignationInput,
+        holidays: Option<&HolidayMap>,
+    ) -> Result<Resignation> {
+        let mut tx = pool.begin().await?;
+        let resignation = Self::insert_with_connection(&mut tx, input, holidays).await?;
+        tx.commit().await?;
+
+        Ok(resignation)
+    }
 
-        if !is_holiday(holidays, &vacation_start_date) {
-            remaining_paid_leave_days -= 1;
+    /// 同じ冪等キーで登録済みの場合は、新たに登録せず登録したときの内容を返す。
+    /// 登録後に更新されていても、更新後の内容は返さない
+    #[instrument(skip(pool, input, holidays), err)]
+    pub async fn insert_idempotently(
+        pool: &DbPool,
+        input: &ResignationInput,
+        holidays: Option<&HolidayMap>,
+        idempotency_key: &str,
+    ) -> AnyhowResult<Resignation> {
+        let request = input.fingerprint();
+        IdempotencyKey::delete_expired(pool).await?;
+        if let Some(resignation) = Self::replay(pool, idempotency_key, &request).await? {
+            return Ok(resignation);
         }
 
-        while remaining_paid_leave_days > 0 {
-            let new_date = vacation_start_date
-                .checked_sub_days(Days::new(1))
-                .context("invalid date")?;
-            vacation_start_date = new_date;
+        let mut tx = pool.begin().await?;
+        let resignation = Self::insert_with_connection(&mut tx, input, holidays).await?;
+        let idempotency_key_input =
+            IdempotencyKeyInput::new(idempotency_key, &request, &resignation);
+        match IdempotencyKey::insert(&mut tx, &idempotency_key_input).await {
+            Ok(()) => {
+                tx.commit().await?;
 
-            if !is_holiday(holidays, &new_date) {
-                remaining_paid_leave_days -= 1;
+                Ok(resignation)
             }
+            Err(sqlx::Error::Database(error)) if error.is_unique_violation() => {
+                tx.rollback().await?;
+
+                Self::replay(pool, idempotency_key, &request)
+                    .await?
+                    .context("冪等キーの登録に失敗しました")
+            }
+            Err(error) => Err(error.into()),
         }
+    }
 
-        Ok(vacation_start_date)
+    /// 冪等キーと一緒に保存した、登録したときの退職を返す
+    async fn replay(
+        pool: &DbPool,
+        idempotency_key: &str,
+        request: &str,
+    ) -> AnyhowResult<Option<Resignation>> {
+        let Some(idempotency_key) = IdempotencyKey::fetch(pool, idempotency_key).await? else {
+            return Ok(None);
+        };
+        if idempotency_key.request != request {
+            return Err(AppError::IdempotencyKeyConflict.into());
+        }
+
+        Ok(Some(idempotency_key.resignation()))
     }
-}
 
-fn is_holiday(holidays: &HashMap<String, String>, date: &NaiveDate) -> bool {
-    if holidays.contains_key(&date.to_string()) {
-        return true;
+    async fn insert_with_connection(
+        conn: &mut DbConnection,
+        input: &ResignationInput,
+        holidays: Option<&HolidayMap>,
+    ) -> Result<Resignation> {
+        let resignation = repository::insert(conn, input).await?;
+        resignation.record_revision(conn, holidays).await?;
+
+        Ok(resignation)
     }
-    if date.weekday() == Weekday::Sat {
-        return true;
+
+    #[instrument(skip(pool, input, holidays), err)]
+    pub async fn update(
+        pool: &DbPool,
+        id: i32,
+        input: &ResignationInput,
+        holidays: Option<&HolidayMap>,
+    ) -> Result<Resignation> {
+        let mut tx = pool.begin().await?;
+        let resignation = repository::update(&mut tx, id, input).await?;
+        resignation.record_revision(&mut tx, holidays).await?;
+        tx.commit().await?;
+
+        Ok(resignation)
+    }
+
+    /// 指定したリビジョンの内容を、更新するときの入力にする
+    ///
+    /// 戻すときは、この入力を更新と同じように検証してから `update` で保存する。
+    /// 戻した内容も新しいリビジョンとして記録される。
+    #[instrument(skip(pool), err)]
+    pub async fn revision_input(pool: &DbPool, id: i32, revision: u32) -> Result<ResignationInput> {
+        let revision = ResignationRevision::fetch(pool, id, revision).await?;
+
+        Ok(ResignationInput {
+            retirement_date: revision.retirement_date,
+            remaining_paid_leave_days: revision.remaining_paid_leave_days,
+            calendar: revision.calendar,
+        })
+    }
+
+    async fn record_revision(
+        &self,
+        conn: &mut DbConnection,
+        holidays: Option<&HolidayMap>,
+    ) -> Result<()> {
+        let vacation_start_date = match holidays {
+            Some(holidays) => self.covered_vacation_start_date(holidays).ok(),
+            None => None,
+        };
+        let revision_input = ResignationRevisionInput {
+            resignation_id: self.id,
+            retirement_date: self.retirement_date,
+            remaining_paid_leave_days: self.remaining_paid_leave_days,
+            vacation_start_date,
+            calendar: self.calendar,
+        };
+        ResignationRevision::insert(conn, &revision_input).await
     }
-    if date.weekday() == Weekday::Sun {
-        return true;
+
+    #[instrument(skip(pool, comment), err)]
+    pub async fn transition(
+        pool: &DbPool,
+        id: i32,
+        to: ResignationStatus,
+        changed_by: &str,
+        comment: Option<&str>,
+    ) -> AnyhowResult<Self> {
+        let mut tx = pool.begin().await?;
+        let resignation = repository::fetch_for_update(&mut tx, id).await?;
+
+        if !resignation.status.can_transition_to(to) {
+            return Err(AppError::InvalidStatusTransition {
+                from: resignation.status,
+                to,
+            }
+            .into());
+        }
+
+        repository::update_status(&mut tx, id, to).await?;
+        let history_input = ResignationStatusHistoryInput {
+            resignation_id: id,
+            from_status: resignation.status,
+            to_status: to,
+            changed_by: changed_by.to_string(),
+            comment: comment.map(str::to_string),
+        };
+        ResignationStatusHistory::insert(&mut tx, &history_input).await?;
+        tx.commit().await?;
+
+        Ok(Self::fetch(pool, id).await?)
     }
 
-    false
+    #[instrument(skip_all, fields(id = self.id))]
+    pub fn vacation_start_date(&self, calendar: &BusinessCalendar) -> AnyhowResult<NaiveDate> {
+        if self.remaining_paid_leave_days == 0 {
+            return Err(AppError::NoPaidLeave.into());
+        }
+        // 退職日が営業日の場合は、退職日も有給を使う日に数える
+        let days_before = if calendar.is_business_day(self.retirement_date) {
+            self.remaining_paid_leave_days - 1
+        } else {
+            self.remaining_paid_leave_days
+        };
+        let too_long = AppError::VacationPeriodTooLong {
+            max_days: MAX_VACATION_SEARCH_DAYS,
+        };
+        // 1 営業日遡るには少なくとも 1 日遡るので、遡る前に上限を超えるものを弾ける
+        if days_before > MAX_VACATION_SEARCH_DAYS {
+            return Err(too_long.into());
+        }
+        let vacation_start_date = calendar.sub_business_days(self.retirement_date, days_before)?;
+        let searched_days = (self.retirement_date - vacation_start_date).num_days();
+        if searched_days > MAX_VACATION_SEARCH_DAYS.into() {
+            return Err(too_long.into());
+        }
+
+        Ok(vacation_start_date)
+    }
+
+    /// 休暇開始日。退職日までの期間に祝日のデータがない年が含まれる場合はエラーにする
+    ///
+    /// 休暇開始日や引き継ぎの期限を返すところは、祝日を取り違えないようにすべてこれを通す。
+    pub fn covered_vacation_start_date(&self, holidays: &HolidayMap) -> AnyhowResult<NaiveDate> {
+        let vacation_start_date = self.vacation_start_date(holidays.calendar())?;
+        holidays.ensure_covered(vacation_start_date, self.retirement_date)?;
+
+        Ok(vacation_start_date)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
     use super::*;
     use anyhow::Result;
     use chrono::{NaiveDate, NaiveDateTime};
-    use sqlx::MySqlPool;
     use tokio::test;
 
     use crate::models::resignation::Resignation;
 
-    #[sqlx::test(fixtures("../fixtures/resignation/resignations.sql"))]
-    async fn fetch_latest(pool: MySqlPool) {
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn fetch_latest(pool: DbPool) {
         let resignation = Resignation::fetch_latest(&pool).await.unwrap();
 
         assert_eq!(resignation.id, 2222);
@@ -143,22 +429,17 @@ mod tests {
         );
     }
 
-    #[sqlx::test]
-    async fn insert(pool: MySqlPool) {
-        let resignations = sqlx::query_as!(
-            Resignation,
-            "SELECT id, created_at, remaining_paid_leave_days, retirement_date FROM resignation"
-        )
-        .fetch_all(&pool)
-        .await
-        .unwrap();
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert(pool: DbPool) {
+        let resignations = Resignation::fetch_all(&pool).await.unwrap();
         assert_eq!(resignations.len(), 0);
         let input = ResignationInput {
             retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
             remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
         };
 
-        let result = Resignation::insert(&pool, &input).await;
+        let result = Resignation::insert(&pool, &input, None).await;
 
         assert!(result.is_ok());
         let resignation = result.unwrap();
@@ -167,13 +448,8 @@ mod tests {
             NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
         );
         assert_eq!(resignation.remaining_paid_leave_days, 10);
-        let resignations = sqlx::query_as!(
-            Resignation,
-            "SELECT id, created_at, remaining_paid_leave_days, retirement_date FROM resignation"
-        )
-        .fetch_all(&pool)
-        .await
-        .unwrap();
+        assert_eq!(resignation.status, ResignationStatus::Draft);
+        let resignations = Resignation::fetch_all(&pool).await.unwrap();
         assert_eq!(resignations.len(), 1);
         assert_eq!(
             resignations.first().unwrap().retirement_date,
@@ -182,40 +458,402 @@ mod tests {
         assert_eq!(resignations.first().unwrap().remaining_paid_leave_days, 10);
     }
 
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert_カレンダーを保存すること(pool: DbPool) {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::UsCa,
+        };
+
+        let resignation = Resignation::insert(&pool, &input, None).await.unwrap();
+
+        assert_eq!(resignation.calendar, Calendar::UsCa);
+        let resignation = Resignation::fetch(&pool, resignation.id).await.unwrap();
+        assert_eq!(resignation.calendar, Calendar::UsCa);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert_idempotently(pool: DbPool) {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        };
+
+        let first = Resignation::insert_idempotently(&pool, &input, None, "key")
+            .await
+            .unwrap();
+        let second = Resignation::insert_idempotently(&pool, &input, None, "key")
+            .await
+            .unwrap();
+
+        assert_eq!(first.id, second.id);
+        let resignations = Resignation::fetch_all(&pool).await.unwrap();
+        assert_eq!(resignations.len(), 1);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert_idempotently_登録後に更新された場合_登録時の内容を返すこと(
+        pool: DbPool,
+    ) {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        };
+        let first = Resignation::insert_idempotently(&pool, &input, None, "key")
+            .await
+            .unwrap();
+        let updated_input = ResignationInput {
+            retirement_date: input.retirement_date,
+            remaining_paid_leave_days: 5,
+            calendar: Calendar::Jp,
+        };
+        Resignation::update(&pool, first.id, &updated_input, None)
+            .await
+            .unwrap();
+        Resignation::transition(
+            &pool,
+            first.id,
+            ResignationStatus::Submitted,
+            "yamada",
+            None,
+        )
+        .await
+        .unwrap();
+
+        let second = Resignation::insert_idempotently(&pool, &input, None, "key")
+            .await
+            .unwrap();
+
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.remaining_paid_leave_days, 10);
+        assert_eq!(second.status, ResignationStatus::Draft);
+        assert_eq!(second.created_at, first.created_at);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert_idempotently_異なるリクエストの場合_エラーになること(
+        pool: DbPool,
+    ) {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        };
+        Resignation::insert_idempotently(&pool, &input, None, "key")
+            .await
+            .unwrap();
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days: 5,
+            calendar: Calendar::Jp,
+        };
+
+        let result = Resignation::insert_idempotently(&pool, &input, None, "key").await;
+
+        assert!(result.is_err());
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn transition(pool: DbPool) {
+        let resignation = Resignation::transition(
+            &pool,
+            2222,
+            ResignationStatus::Submitted,
+            "yamada",
+            Some("提出します"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resignation.status, ResignationStatus::Submitted);
+        let histories = ResignationStatusHistory::fetch_by_resignation_id(&pool, 2222)
+            .await
+            .unwrap();
+        assert_eq!(histories.len(), 1);
+        assert_eq!(histories[0].from_status, ResignationStatus::Draft);
+        assert_eq!(histories[0].to_status, ResignationStatus::Submitted);
+        assert_eq!(histories[0].changed_by, "yamada");
+        assert_eq!(histories[0].comment.as_deref(), Some("提出します"));
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn transition_許可されていない遷移の場合_エラーになること(
+        pool: DbPool,
+    ) {
+        let result =
+            Resignation::transition(&pool, 2222, ResignationStatus::Approved, "yamada", None).await;
+
+        assert!(result.is_err());
+        let resignation = Resignation::fetch(&pool, 2222).await.unwrap();
+        assert_eq!(resignation.status, ResignationStatus::Draft);
+        let histories = ResignationStatusHistory::fetch_by_resignation_id(&pool, 2222)
+            .await
+            .unwrap();
+        assert_eq!(histories.len(), 0);
+    }
+
+    #[test]
+    async fn can_transition_to() {
+        use ResignationStatus::*;
+
+        assert!(Draft.can_transition_to(Submitted));
+        assert!(Submitted.can_transition_to(Approved));
+        assert!(Submitted.can_transition_to(Rejected));
+        assert!(Approved.can_transition_to(Withdrawn));
+        assert!(Approved.can_transition_to(Completed));
+        assert!(!Draft.can_transition_to(Approved));
+        assert!(!Rejected.can_transition_to(Approved));
+        assert!(!Withdrawn.can_transition_to(Submitted));
+        assert!(!Completed.can_transition_to(Withdrawn));
+    }
+
     #[test]
     async fn vacation_start_date_error() -> Result<()> {
         let resignation = Resignation {
             id: 1,
             retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
             remaining_paid_leave_days: 0,
+            calendar: Calendar::Jp,
+            status: ResignationStatus::Draft,
             created_at: NaiveDateTime::parse_from_str("2025-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")?,
         };
-        let holidays = HashMap::new();
+        let holidays = BusinessCalendar::default();
 
-        let result = resignation.vacation_start_date(&holidays).await;
+        let result = resignation.vacation_start_date(&holidays);
 
         assert!(result.is_err());
 
         Ok(())
     }
 
+    #[test]
+    async fn vacation_start_date_休暇期間が長すぎる場合_エラーになること() -> Result<()> {
+        let resignation = Resignation {
+            id: 1,
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days: 4_000_000,
+            calendar: Calendar::Jp,
+            status: ResignationStatus::Draft,
+            created_at: NaiveDateTime::parse_from_str("2025-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")?,
+        };
+
+        let result = resignation.vacation_start_date(&BusinessCalendar::default());
+
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast_ref::<AppError>()
+                .unwrap()
+                .code(),
+            "VACATION_PERIOD_TOO_LONG"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn covered_vacation_start_date_祝日のデータがない年にかかる場合_エラーになること()
+    -> Result<()> {
+        let resignation = Resignation {
+            id: 1,
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(),
+            remaining_paid_leave_days: 5,
+            calendar: Calendar::Jp,
+            status: ResignationStatus::Draft,
+            created_at: NaiveDateTime::parse_from_str("2025-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")?,
+        };
+
+        let covered = resignation.covered_vacation_start_date(&HolidayMap::new(
+            HashMap::new(),
+            BTreeSet::from([2024, 2025]),
+        ));
+        let uncovered = resignation
+            .covered_vacation_start_date(&HolidayMap::new(HashMap::new(), BTreeSet::from([2025])));
+
+        assert_eq!(covered?, NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+        assert_eq!(
+            uncovered
+                .unwrap_err()
+                .downcast_ref::<AppError>()
+                .unwrap()
+                .code(),
+            "HOLIDAYS_NOT_COVERED"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn validate_上限を超える場合_エラーになること() {
+        let config = PaidLeaveConfig::default();
+        let input = |remaining_paid_leave_days| ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days,
+            calendar: Calendar::Jp,
+        };
+
+        let today = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+        assert!(input(40).validate(&config, today).is_ok());
+        assert!(input(41).validate(&config, today).is_err());
+    }
+
+    #[test]
+    async fn validate_退職日が今日以前の場合_エラーになること() {
+        let config = PaidLeaveConfig::default();
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        };
+
+        let result = input.validate(&config, NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+
+        assert_eq!(result.unwrap_err().code(), "VALIDATION_FAILED");
+    }
+
+    fn year_end_holidays() -> HolidayMap {
+        HolidayMap::new(
+            HashMap::from([
+                (
+                    NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+                    "休み".to_string(),
+                ),
+                (
+                    NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                    "元日".to_string(),
+                ),
+            ]),
+            BTreeSet::from([2024, 2025]),
+        )
+    }
+
+    fn input(retirement_date: &str) -> ResignationInput {
+        ResignationInput {
+            retirement_date: retirement_date.parse().unwrap(),
+            remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+        }
+    }
+
+    fn app_error_code(error: anyhow::Error) -> &'static str {
+        error.downcast_ref::<AppError>().unwrap().code()
+    }
+
+    #[test]
+    async fn apply_retirement_date_policy_営業日にずらす場合_ずらした結果を返すこと() {
+        let holidays = year_end_holidays();
+        let today = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+        let mut previous = input("2025-01-01");
+        let adjustment = previous
+            .apply_retirement_date_policy(
+                RetirementDatePolicy::PreviousBusinessDay,
+                Some(&holidays),
+                today,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(adjustment.requested_date, "2025-01-01".parse().unwrap());
+        assert_eq!(adjustment.adjusted_date, "2024-12-30".parse().unwrap());
+        assert_eq!(previous.retirement_date, adjustment.adjusted_date);
+
+        let mut next = input("2025-01-04");
+        let adjustment = next
+            .apply_retirement_date_policy(
+                RetirementDatePolicy::NextBusinessDay,
+                Some(&holidays),
+                today,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(adjustment.adjusted_date, "2025-01-06".parse().unwrap());
+        assert_eq!(next.retirement_date, adjustment.adjusted_date);
+    }
+
+    #[test]
+    async fn apply_retirement_date_policy_ずらさない場合_退職日を変えないこと() {
+        let holidays = year_end_holidays();
+        let today = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+        let mut allowed = input("2025-01-01");
+        let adjustment = allowed
+            .apply_retirement_date_policy(RetirementDatePolicy::Allow, None, today)
+            .unwrap();
+        assert!(adjustment.is_none());
+        assert_eq!(allowed.retirement_date, "2025-01-01".parse().unwrap());
+
+        let mut business_day = input("2025-01-06");
+        let adjustment = business_day
+            .apply_retirement_date_policy(
+                RetirementDatePolicy::NextBusinessDay,
+                Some(&holidays),
+                today,
+            )
+            .unwrap();
+        assert!(adjustment.is_none());
+        assert_eq!(business_day.retirement_date, "2025-01-06".parse().unwrap());
+    }
+
+    #[test]
+    async fn apply_retirement_date_policy_適用できない場合_エラーになること() {
+        let holidays = year_end_holidays();
+        let today = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+        let error = input("2025-01-01")
+            .apply_retirement_date_policy(RetirementDatePolicy::Reject, Some(&holidays), today)
+            .unwrap_err();
+        assert_eq!(app_error_code(error), "VALIDATION_FAILED");
+
+        let error = input("2025-01-01")
+            .apply_retirement_date_policy(
+                RetirementDatePolicy::PreviousBusinessDay,
+                Some(&holidays),
+                NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(),
+            )
+            .unwrap_err();
+        assert_eq!(app_error_code(error), "VALIDATION_FAILED");
+
+        let error = input("2025-01-01")
+            .apply_retirement_date_policy(RetirementDatePolicy::Reject, None, today)
+            .unwrap_err();
+        assert_eq!(app_error_code(error), "HOLIDAY_SOURCE_UNAVAILABLE");
+
+        let error = input("2030-01-01")
+            .apply_retirement_date_policy(RetirementDatePolicy::Reject, Some(&holidays), today)
+            .unwrap_err();
+        assert_eq!(app_error_code(error), "HOLIDAYS_NOT_COVERED");
+    }
+
     #[test]
     async fn vacation_start_date() -> Result<()> {
         let resignation = Resignation {
             id: 1,
             retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
             remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+            status: ResignationStatus::Draft,
             created_at: NaiveDateTime::parse_from_str("2025-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")?,
         };
         // 土曜日: 2024-12-28, 2024-12-21, 2024-12-14
         // 日曜日: 2024-12-29, 2024-12-22, 2024-12-15
-        let mut holidays = HashMap::new();
-        holidays.insert("2025-01-01".to_string(), "休み".to_string());
-        holidays.insert("2024-12-31".to_string(), "休み".to_string());
-        holidays.insert("2024-12-29".to_string(), "休み".to_string());
-        holidays.insert("2024-12-28".to_string(), "休み".to_string());
+        let holidays = BusinessCalendar::new([
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 28).unwrap(),
+        ]);
 
-        let result = resignation.vacation_start_date(&holidays).await;
+        let result = resignation.vacation_start_date(&holidays);
 
         println!("{:?}", result.iter().clone());
         assert!(result.is_ok());
@@ -231,16 +869,19 @@ mod tests {
             id: 1,
             retirement_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
             remaining_paid_leave_days: 10,
+            calendar: Calendar::Jp,
+            status: ResignationStatus::Draft,
             created_at: NaiveDateTime::parse_from_str("2025-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")?,
         };
         // 土曜日: 2024-12-28, 2024-12-21, 2024-12-14
         // 日曜日: 2024-12-29, 2024-12-22, 2024-12-15
-        let mut holidays = HashMap::new();
-        holidays.insert("2024-12-31".to_string(), "休み".to_string());
-        holidays.insert("2024-12-29".to_string(), "休み".to_string());
-        holidays.insert("2024-12-28".to_string(), "休み".to_string());
+        let holidays = BusinessCalendar::new([
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 28).unwrap(),
+        ]);
 
-        let result = resignation.vacation_start_date(&holidays).await;
+        let result = resignation.vacation_start_date(&holidays);
 
         println!("{:?}", result.iter().clone());
         assert!(result.is_ok());
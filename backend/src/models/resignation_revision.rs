@@ -0,0 +1,130 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::Result;
+
+use crate::{
+    calendars::Calendar,
+    infrastructure::database::{DbConnection, DbPool},
+    repositories::resignation_revision as repository,
+};
+
+pub struct ResignationRevision {
+    pub id: i32,
+    pub resignation_id: i32,
+    pub revision: u32,
+    pub retirement_date: NaiveDate,
+    pub remaining_paid_leave_days: u32,
+    /// 記録した時点で計算した休暇開始日。祝日を取得できなかった場合などは None
+    pub vacation_start_date: Option<NaiveDate>,
+    /// 記録した時点の休日のカレンダー
+    pub calendar: Calendar,
+    pub created_at: NaiveDateTime,
+}
+
+pub struct ResignationRevisionInput {
+    pub resignation_id: i32,
+    pub retirement_date: NaiveDate,
+    pub remaining_paid_leave_days: u32,
+    pub vacation_start_date: Option<NaiveDate>,
+    pub calendar: Calendar,
+}
+
+impl ResignationRevision {
+    pub async fn fetch(pool: &DbPool, resignation_id: i32, revision: u32) -> Result<Self> {
+        repository::fetch(pool, resignation_id, revision).await
+    }
+
+    pub async fn fetch_by_resignation_id(pool: &DbPool, resignation_id: i32) -> Result<Vec<Self>> {
+        repository::fetch_by_resignation_id(pool, resignation_id).await
+    }
+
+    pub async fn insert(conn: &mut DbConnection, input: &ResignationRevisionInput) -> Result<()> {
+        repository::insert(conn, input).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
+    use chrono::NaiveDate;
+
+    use crate::{
+        calendars::Calendar,
+        infrastructure::database::DbPool,
+        models::{
+            holiday::HolidayMap,
+            resignation::{Resignation, ResignationInput},
+            resignation_revision::ResignationRevision,
+        },
+    };
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert_update_revert(pool: DbPool) {
+        let holidays = HolidayMap::new(HashMap::new(), BTreeSet::from([2025]));
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            remaining_paid_leave_days: 1,
+            calendar: Calendar::Jp,
+        };
+        let resignation = Resignation::insert(&pool, &input, Some(&holidays))
+            .await
+            .unwrap();
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+            remaining_paid_leave_days: 0,
+            calendar: Calendar::UsCa,
+        };
+        Resignation::update(&pool, resignation.id, &input, Some(&holidays))
+            .await
+            .unwrap();
+
+        let input = Resignation::revision_input(&pool, resignation.id, 1)
+            .await
+            .unwrap();
+        let resignation = Resignation::update(&pool, resignation.id, &input, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            resignation.retirement_date,
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+        );
+        assert_eq!(resignation.remaining_paid_leave_days, 1);
+        assert_eq!(resignation.calendar, Calendar::Jp);
+        let revisions = ResignationRevision::fetch_by_resignation_id(&pool, resignation.id)
+            .await
+            .unwrap();
+        assert_eq!(revisions.len(), 3);
+        assert_eq!(revisions[0].revision, 1);
+        assert_eq!(
+            revisions[0].vacation_start_date,
+            Some(NaiveDate::from_ymd_opt(2025, 1, 31).unwrap())
+        );
+        assert_eq!(revisions[1].revision, 2);
+        assert_eq!(revisions[1].remaining_paid_leave_days, 0);
+        assert_eq!(revisions[1].vacation_start_date, None);
+        assert_eq!(revisions[1].calendar, Calendar::UsCa);
+        assert_eq!(revisions[2].revision, 3);
+        assert_eq!(
+            revisions[2].retirement_date,
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+        );
+        assert_eq!(revisions[2].vacation_start_date, None);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn revision_input_存在しないリビジョンの場合_エラーになること(
+        pool: DbPool,
+    ) {
+        let input = ResignationInput {
+            retirement_date: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            remaining_paid_leave_days: 1,
+            calendar: Calendar::Jp,
+        };
+        let resignation = Resignation::insert(&pool, &input, None).await.unwrap();
+
+        let result = Resignation::revision_input(&pool, resignation.id, 2).await;
+
+        assert!(result.is_err());
+    }
+}
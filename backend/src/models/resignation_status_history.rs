@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use sqlx::Result;
+
+use crate::{
+    infrastructure::database::{DbConnection, DbPool},
+    models::resignation::ResignationStatus,
+    repositories::resignation_status_history as repository,
+};
+
+pub struct ResignationStatusHistory {
+    pub id: i32,
+    pub resignation_id: i32,
+    pub from_status: ResignationStatus,
+    pub to_status: ResignationStatus,
+    pub changed_by: String,
+    pub comment: Option<String>,
+    pub changed_at: NaiveDateTime,
+}
+
+pub struct ResignationStatusHistoryInput {
+    pub resignation_id: i32,
+    pub from_status: ResignationStatus,
+    pub to_status: ResignationStatus,
+    pub changed_by: String,
+    pub comment: Option<String>,
+}
+
+impl ResignationStatusHistory {
+    pub async fn fetch_by_resignation_id(pool: &DbPool, resignation_id: i32) -> Result<Vec<Self>> {
+        repository::fetch_by_resignation_id(pool, resignation_id).await
+    }
+
+    pub async fn insert(
+        conn: &mut DbConnection,
+        input: &ResignationStatusHistoryInput,
+    ) -> Result<()> {
+        repository::insert(conn, input).await
+    }
+}
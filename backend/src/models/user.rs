@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use sqlx::Result;
+
+use crate::{infrastructure::database::DbPool, repositories::user as repository};
+
+/// 利用者。`X-Actor` で名乗る名前と、管理者かどうかを持つ
+pub struct User {
+    pub id: i32,
+    pub name: String,
+    pub is_admin: bool,
+    pub created_at: NaiveDateTime,
+}
+
+pub struct UserInput {
+    pub name: String,
+    pub is_admin: bool,
+}
+
+impl User {
+    pub async fn fetch_all(pool: &DbPool) -> Result<Vec<Self>> {
+        repository::fetch_all(pool).await
+    }
+
+    pub async fn insert(pool: &DbPool, input: &UserInput) -> Result<Self> {
+        repository::insert(pool, input).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn insert_fetch_all(pool: DbPool) {
+        let input = UserInput {
+            name: "yamada".to_string(),
+            is_admin: false,
+        };
+
+        let user = User::insert(&pool, &input).await.unwrap();
+
+        assert_eq!(user.name, "yamada");
+        assert!(!user.is_admin);
+        let users = User::fetch_all(&pool).await.unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].id, user.id);
+        assert!(User::insert(&pool, &input).await.is_err());
+    }
+}
@@ -0,0 +1,19 @@
+//! テーブルごとの SQL をまとめた層。有効にした DB の feature に対応する実装だけをコンパイルする。
+//!
+//! MySQL は `query!` マクロでコンパイル時にクエリを検証する。PostgreSQL と SQLite は
+//! DB サーバーなしでもビルドできるよう、実行時に組み立てるクエリと行の構造体で実装する。
+
+#[cfg(feature = "mysql")]
+mod mysql;
+#[cfg(feature = "mysql")]
+pub use mysql::*;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "postgres")]
+pub use postgres::*;
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(feature = "sqlite")]
+pub use sqlite::*;
@@ -0,0 +1,65 @@
+use sqlx::{MySql, Pool, Result};
+
+use crate::{
+    models::audit_log::{AuditLog, AuditLogFilter, AuditLogInput},
+    utils::time::now,
+};
+
+pub async fn fetch_all(pool: &Pool<MySql>, filter: &AuditLogFilter) -> Result<Vec<AuditLog>> {
+    let audit_logs = sqlx::query_as!(
+        AuditLog,
+        r#"
+            SELECT
+                id, operation_name, variables, claimed_actor, client_ip, succeeded, errors, created_at
+            FROM
+                audit_log
+            WHERE
+                (? IS NULL OR operation_name = ?)
+                AND (? IS NULL OR claimed_actor = ?)
+                AND (? IS NULL OR succeeded = ?)
+                AND (? IS NULL OR created_at >= ?)
+                AND (? IS NULL OR created_at <= ?)
+            ORDER BY
+                created_at DESC, id DESC
+            LIMIT ?
+        "#,
+        filter.operation_name,
+        filter.operation_name,
+        filter.claimed_actor,
+        filter.claimed_actor,
+        filter.succeeded,
+        filter.succeeded,
+        filter.from,
+        filter.from,
+        filter.to,
+        filter.to,
+        filter.limit
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(audit_logs)
+}
+
+pub async fn insert(pool: &Pool<MySql>, input: &AuditLogInput) -> Result<()> {
+    let now = now();
+    sqlx::query!(
+        r#"
+        INSERT INTO
+            audit_log (operation_name, variables, claimed_actor, client_ip, succeeded, errors, created_at)
+        VALUES
+            (?, ?, ?, ?, ?, ?, ?)
+        "#,
+        input.operation_name,
+        input.variables,
+        input.claimed_actor,
+        input.client_ip,
+        input.succeeded,
+        input.errors,
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use sqlx::{MySql, Pool, Result};
+
+use crate::{
+    models::handover_task::{HandoverTask, HandoverTaskInput},
+    utils::time::now,
+};
+
+pub async fn fetch(pool: &Pool<MySql>, id: i32) -> Result<HandoverTask> {
+    let handover_task = sqlx::query_as!(
+        HandoverTask,
+        r#"
+            SELECT
+                id, resignation_id, title, working_days_before, completed, created_at
+            FROM
+                handover_task
+            WHERE
+                id = ?
+        "#,
+        id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(handover_task)
+}
+
+pub async fn fetch_by_resignation_id(
+    pool: &Pool<MySql>,
+    resignation_id: i32,
+) -> Result<Vec<HandoverTask>> {
+    let handover_tasks = sqlx::query_as!(
+        HandoverTask,
+        r#"
+            SELECT
+                id, resignation_id, title, working_days_before, completed, created_at
+            FROM
+                handover_task
+            WHERE
+                resignation_id = ?
+            ORDER BY
+                working_days_before DESC, id ASC
+        "#,
+        resignation_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(handover_tasks)
+}
+
+pub async fn insert(pool: &Pool<MySql>, input: &HandoverTaskInput) -> Result<HandoverTask> {
+    let now = now();
+    let id = sqlx::query!(
+        r#"
+        INSERT INTO
+            handover_task (resignation_id, title, working_days_before, completed, created_at)
+        VALUES
+            (?, ?, ?, FALSE, ?)
+        "#,
+        input.resignation_id,
+        input.title,
+        input.working_days_before,
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(pool)
+    .await?
+    .last_insert_id();
+
+    fetch(pool, id as i32).await
+}
+
+pub async fn update(pool: &Pool<MySql>, handover_task: &HandoverTask) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE
+            handover_task
+        SET
+            title = ?, working_days_before = ?, completed = ?
+        WHERE
+            id = ?
+        "#,
+        handover_task.title,
+        handover_task.working_days_before,
+        handover_task.completed,
+        handover_task.id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete(pool: &Pool<MySql>, id: i32) -> Result<()> {
+    sqlx::query!(
+        r#"
+        DELETE FROM
+            handover_task
+        WHERE
+            id = ?
+        "#,
+        id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
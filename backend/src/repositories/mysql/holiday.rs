@@ -0,0 +1,49 @@
+use sqlx::{MySql, Pool, Result};
+
+use crate::{
+    calendars::Calendar,
+    models::holiday::{Holiday, HolidaySource},
+};
+
+pub async fn fetch_all(pool: &Pool<MySql>, calendar: Calendar) -> Result<Vec<Holiday>> {
+    let holidays = sqlx::query_as!(
+        Holiday,
+        r#"
+            SELECT
+                holiday_date AS date, name, source AS `source: HolidaySource`
+            FROM
+                holiday
+            WHERE
+                calendar = ?
+            ORDER BY
+                holiday_date ASC
+        "#,
+        calendar.code()
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(holidays)
+}
+
+/// 同じ日の休日が既にある場合は、名前と種類を置き換える
+pub async fn upsert(pool: &Pool<MySql>, calendar: Calendar, holiday: &Holiday) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO
+            holiday (calendar, holiday_date, name, source)
+        VALUES
+            (?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            name = VALUES(name), source = VALUES(source)
+        "#,
+        calendar.code(),
+        holiday.date.to_string(),
+        holiday.name,
+        holiday.source.as_str()
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
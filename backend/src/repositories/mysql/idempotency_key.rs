@@ -0,0 +1,87 @@
+use chrono::TimeDelta;
+use sqlx::{MySql, MySqlConnection, Pool, Result};
+
+use crate::{
+    calendars::Calendar,
+    consts::idempotency_key::IDEMPOTENCY_KEY_TTL_HOURS,
+    models::{
+        idempotency_key::{IdempotencyKey, IdempotencyKeyInput},
+        resignation::ResignationStatus,
+    },
+    utils::time::now,
+};
+
+pub async fn fetch(pool: &Pool<MySql>, idempotency_key: &str) -> Result<Option<IdempotencyKey>> {
+    let now = now();
+    let idempotency_key = sqlx::query_as!(
+        IdempotencyKey,
+        r#"
+            SELECT
+                idempotency_key, request, resignation_id, retirement_date,
+                remaining_paid_leave_days, calendar AS `calendar: Calendar`,
+                status AS `status: ResignationStatus`, resignation_created_at,
+                created_at, expires_at
+            FROM
+                idempotency_key
+            WHERE
+                idempotency_key = ? AND expires_at > ?
+        "#,
+        idempotency_key,
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(idempotency_key)
+}
+
+pub async fn insert(conn: &mut MySqlConnection, input: &IdempotencyKeyInput) -> Result<()> {
+    let now = now();
+    let expires_at = now + TimeDelta::hours(IDEMPOTENCY_KEY_TTL_HOURS);
+    sqlx::query!(
+        r#"
+        INSERT INTO
+            idempotency_key (
+                idempotency_key, request, resignation_id, retirement_date,
+                remaining_paid_leave_days, calendar, status, resignation_created_at,
+                created_at, expires_at
+            )
+        VALUES
+            (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        input.idempotency_key,
+        input.request,
+        input.resignation_id,
+        input.retirement_date.to_string(),
+        input.remaining_paid_leave_days,
+        input.calendar.code(),
+        input.status.as_str(),
+        input
+            .resignation_created_at
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+        now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        expires_at.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_expired(pool: &Pool<MySql>) -> Result<()> {
+    let now = now();
+    sqlx::query!(
+        r#"
+        DELETE FROM
+            idempotency_key
+        WHERE
+            expires_at <= ?
+        "#,
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use sqlx::{MySql, Pool, Result};
+
+use crate::{models::persisted_query::PersistedQuery, utils::time::now};
+
+pub async fn fetch(pool: &Pool<MySql>, sha256_hash: &str) -> Result<Option<PersistedQuery>> {
+    let persisted_query = sqlx::query_as!(
+        PersistedQuery,
+        r#"
+            SELECT
+                sha256_hash, query, created_at
+            FROM
+                persisted_query
+            WHERE
+                sha256_hash = ?
+        "#,
+        sha256_hash
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(persisted_query)
+}
+
+pub async fn insert(pool: &Pool<MySql>, sha256_hash: &str, query: &str) -> Result<()> {
+    let now = now();
+    sqlx::query!(
+        r#"
+        INSERT IGNORE INTO
+            persisted_query (sha256_hash, query, created_at)
+        VALUES
+            (?, ?, ?)
+        "#,
+        sha256_hash,
+        query,
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn count(pool: &Pool<MySql>) -> Result<i64> {
+    let count = sqlx::query_scalar!(
+        r#"
+            SELECT
+                COUNT(*)
+            FROM
+                persisted_query
+        "#
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
@@ -0,0 +1,194 @@
+use sqlx::{MySql, MySqlConnection, Pool, Result};
+
+use crate::{
+    calendars::Calendar,
+    models::resignation::{Resignation, ResignationInput, ResignationStatus},
+    utils::time::now,
+};
+
+pub async fn fetch_latest(pool: &Pool<MySql>) -> Result<Resignation> {
+    let latest_resignation = sqlx::query_as!(
+        Resignation,
+        r#"
+            SELECT
+                id, retirement_date, remaining_paid_leave_days,
+                status AS `status: ResignationStatus`, calendar AS `calendar: Calendar`,
+            created_at
+            FROM
+                resignation
+            ORDER BY
+                created_at DESC
+            LIMIT 1
+        "#
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(latest_resignation)
+}
+
+pub async fn fetch_all(pool: &Pool<MySql>) -> Result<Vec<Resignation>> {
+    let resignations = sqlx::query_as!(
+        Resignation,
+        r#"
+            SELECT
+                id, retirement_date, remaining_paid_leave_days,
+                status AS `status: ResignationStatus`, calendar AS `calendar: Calendar`,
+            created_at
+            FROM
+                resignation
+            ORDER BY
+                created_at DESC, id DESC
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(resignations)
+}
+
+pub async fn fetch(pool: &Pool<MySql>, id: i32) -> Result<Resignation> {
+    let resignation = sqlx::query_as!(
+        Resignation,
+        r#"
+            SELECT
+                id, retirement_date, remaining_paid_leave_days,
+                status AS `status: ResignationStatus`, calendar AS `calendar: Calendar`,
+            created_at
+            FROM
+                resignation
+            WHERE
+                id = ?
+        "#,
+        id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(resignation)
+}
+
+pub async fn fetch_for_update(conn: &mut MySqlConnection, id: i32) -> Result<Resignation> {
+    let resignation = sqlx::query_as!(
+        Resignation,
+        r#"
+        SELECT
+            id, retirement_date, remaining_paid_leave_days,
+            status AS `status: ResignationStatus`, calendar AS `calendar: Calendar`,
+            created_at
+        FROM
+            resignation
+        WHERE
+            id = ?
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(resignation)
+}
+
+pub async fn insert(conn: &mut MySqlConnection, input: &ResignationInput) -> Result<Resignation> {
+    let now = now();
+    let id = sqlx::query!(
+        r#"
+        INSERT INTO
+            resignation (retirement_date, remaining_paid_leave_days, calendar, created_at)
+        VALUES
+            (?, ?, ?, ?)
+        "#,
+        input.retirement_date.to_string(),
+        input.remaining_paid_leave_days,
+        input.calendar.code(),
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(&mut *conn)
+    .await?
+    .last_insert_id();
+
+    let resignation = sqlx::query_as!(
+        Resignation,
+        r#"
+        SELECT
+            id, retirement_date, remaining_paid_leave_days,
+            status AS `status: ResignationStatus`, calendar AS `calendar: Calendar`,
+            created_at
+        FROM
+            resignation
+        WHERE
+            ID = ?
+    "#,
+        id
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(resignation)
+}
+
+pub async fn update(
+    conn: &mut MySqlConnection,
+    id: i32,
+    input: &ResignationInput,
+) -> Result<Resignation> {
+    sqlx::query!(
+        r#"
+        UPDATE
+            resignation
+        SET
+            retirement_date = ?, remaining_paid_leave_days = ?, calendar = ?
+        WHERE
+            id = ?
+        "#,
+        input.retirement_date.to_string(),
+        input.remaining_paid_leave_days,
+        input.calendar.code(),
+        id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let resignation = sqlx::query_as!(
+        Resignation,
+        r#"
+        SELECT
+            id, retirement_date, remaining_paid_leave_days,
+            status AS `status: ResignationStatus`, calendar AS `calendar: Calendar`,
+            created_at
+        FROM
+            resignation
+        WHERE
+            id = ?
+        "#,
+        id
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(resignation)
+}
+
+pub async fn update_status(
+    conn: &mut MySqlConnection,
+    id: i32,
+    status: ResignationStatus,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE
+            resignation
+        SET
+            status = ?
+        WHERE
+            id = ?
+        "#,
+        status,
+        id
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
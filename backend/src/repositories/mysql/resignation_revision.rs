@@ -0,0 +1,89 @@
+use sqlx::{MySql, MySqlConnection, Pool, Result};
+
+use crate::{
+    calendars::Calendar,
+    models::resignation_revision::{ResignationRevision, ResignationRevisionInput},
+    utils::time::now,
+};
+
+pub async fn fetch(
+    pool: &Pool<MySql>,
+    resignation_id: i32,
+    revision: u32,
+) -> Result<ResignationRevision> {
+    let resignation_revision = sqlx::query_as!(
+        ResignationRevision,
+        r#"
+            SELECT
+                id, resignation_id, revision, retirement_date,
+                remaining_paid_leave_days, vacation_start_date,
+                calendar AS `calendar: Calendar`, created_at
+            FROM
+                resignation_revision
+            WHERE
+                resignation_id = ? AND revision = ?
+        "#,
+        resignation_id,
+        revision
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(resignation_revision)
+}
+
+pub async fn fetch_by_resignation_id(
+    pool: &Pool<MySql>,
+    resignation_id: i32,
+) -> Result<Vec<ResignationRevision>> {
+    let resignation_revisions = sqlx::query_as!(
+        ResignationRevision,
+        r#"
+            SELECT
+                id, resignation_id, revision, retirement_date,
+                remaining_paid_leave_days, vacation_start_date,
+                calendar AS `calendar: Calendar`, created_at
+            FROM
+                resignation_revision
+            WHERE
+                resignation_id = ?
+            ORDER BY
+                revision ASC
+        "#,
+        resignation_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(resignation_revisions)
+}
+
+pub async fn insert(conn: &mut MySqlConnection, input: &ResignationRevisionInput) -> Result<()> {
+    let now = now();
+    sqlx::query!(
+        r#"
+        INSERT INTO
+            resignation_revision (
+                resignation_id, revision, retirement_date,
+                remaining_paid_leave_days, vacation_start_date, calendar, created_at
+            )
+        SELECT
+            ?, COALESCE(MAX(revision), 0) + 1, ?, ?, ?, ?, ?
+        FROM
+            resignation_revision
+        WHERE
+            resignation_id = ?
+        "#,
+        input.resignation_id,
+        input.retirement_date.to_string(),
+        input.remaining_paid_leave_days,
+        input.vacation_start_date.map(|date| date.to_string()),
+        input.calendar.code(),
+        now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        input.resignation_id
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use sqlx::{MySql, MySqlConnection, Pool, Result};
+
+use crate::{
+    models::{
+        resignation::ResignationStatus,
+        resignation_status_history::{ResignationStatusHistory, ResignationStatusHistoryInput},
+    },
+    utils::time::now,
+};
+
+pub async fn fetch_by_resignation_id(
+    pool: &Pool<MySql>,
+    resignation_id: i32,
+) -> Result<Vec<ResignationStatusHistory>> {
+    let histories = sqlx::query_as!(
+        ResignationStatusHistory,
+        r#"
+            SELECT
+                id, resignation_id,
+                from_status AS `from_status: ResignationStatus`,
+                to_status AS `to_status: ResignationStatus`,
+                changed_by, comment, changed_at
+            FROM
+                resignation_status_history
+            WHERE
+                resignation_id = ?
+            ORDER BY
+                changed_at ASC, id ASC
+        "#,
+        resignation_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(histories)
+}
+
+pub async fn insert(
+    conn: &mut MySqlConnection,
+    input: &ResignationStatusHistoryInput,
+) -> Result<()> {
+    let now = now();
+    sqlx::query!(
+        r#"
+        INSERT INTO
+            resignation_status_history
+            (resignation_id, from_status, to_status, changed_by, comment, changed_at)
+        VALUES
+            (?, ?, ?, ?, ?, ?)
+        "#,
+        input.resignation_id,
+        input.from_status,
+        input.to_status,
+        input.changed_by,
+        input.comment,
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
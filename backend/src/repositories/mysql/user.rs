@@ -0,0 +1,59 @@
+use sqlx::{MySql, Pool, Result};
+
+use crate::{
+    models::user::{User, UserInput},
+    utils::time::now,
+};
+
+pub async fn fetch_all(pool: &Pool<MySql>) -> Result<Vec<User>> {
+    let users = sqlx::query_as!(
+        User,
+        r#"
+            SELECT
+                id, name, is_admin, created_at
+            FROM
+                app_user
+            ORDER BY
+                id ASC
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(users)
+}
+
+pub async fn insert(pool: &Pool<MySql>, input: &UserInput) -> Result<User> {
+    let now = now();
+    let id = sqlx::query!(
+        r#"
+        INSERT INTO
+            app_user (name, is_admin, created_at)
+        VALUES
+            (?, ?, ?)
+        "#,
+        input.name,
+        input.is_admin,
+        now.format("%Y-%m-%d %H:%M:%S").to_string()
+    )
+    .execute(pool)
+    .await?
+    .last_insert_id();
+
+    let user = sqlx::query_as!(
+        User,
+        r#"
+        SELECT
+            id, name, is_admin, created_at
+        FROM
+            app_user
+        WHERE
+            id = ?
+        "#,
+        id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(user)
+}
@@ -0,0 +1,129 @@
+use chrono::NaiveDateTime;
+use sqlx::{FromRow, Pool, Postgres, Result};
+
+use crate::{
+    models::handover_task::{HandoverTask, HandoverTaskInput},
+    utils::time::now,
+};
+
+#[derive(FromRow)]
+struct HandoverTaskRow {
+    id: i32,
+    resignation_id: i32,
+    title: String,
+    working_days_before: i32,
+    completed: bool,
+    created_at: NaiveDateTime,
+}
+
+impl From<HandoverTaskRow> for HandoverTask {
+    fn from(row: HandoverTaskRow) -> Self {
+        Self {
+            id: row.id,
+            resignation_id: row.resignation_id,
+            title: row.title,
+            working_days_before: row.working_days_before as u32,
+            completed: row.completed,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub async fn fetch(pool: &Pool<Postgres>, id: i32) -> Result<HandoverTask> {
+    let handover_task = sqlx::query_as::<_, HandoverTaskRow>(
+        r#"
+            SELECT
+                id, resignation_id, title, working_days_before, completed, created_at
+            FROM
+                handover_task
+            WHERE
+                id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(handover_task.into())
+}
+
+pub async fn fetch_by_resignation_id(
+    pool: &Pool<Postgres>,
+    resignation_id: i32,
+) -> Result<Vec<HandoverTask>> {
+    let handover_tasks = sqlx::query_as::<_, HandoverTaskRow>(
+        r#"
+            SELECT
+                id, resignation_id, title, working_days_before, completed, created_at
+            FROM
+                handover_task
+            WHERE
+                resignation_id = $1
+            ORDER BY
+                working_days_before DESC, id ASC
+        "#,
+    )
+    .bind(resignation_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(handover_tasks.into_iter().map(Into::into).collect())
+}
+
+pub async fn insert(pool: &Pool<Postgres>, input: &HandoverTaskInput) -> Result<HandoverTask> {
+    let handover_task = sqlx::query_as::<_, HandoverTaskRow>(
+        r#"
+        INSERT INTO
+            handover_task (resignation_id, title, working_days_before, completed, created_at)
+        VALUES
+            ($1, $2, $3, FALSE, $4)
+        RETURNING
+            id, resignation_id, title, working_days_before, completed, created_at
+        "#,
+    )
+    .bind(input.resignation_id)
+    .bind(&input.title)
+    .bind(input.working_days_before as i32)
+    .bind(now())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(handover_task.into())
+}
+
+pub async fn update(pool: &Pool<Postgres>, handover_task: &HandoverTask) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE
+            handover_task
+        SET
+            title = $1, working_days_before = $2, completed = $3
+        WHERE
+            id = $4
+        "#,
+    )
+    .bind(&handover_task.title)
+    .bind(handover_task.working_days_before as i32)
+    .bind(handover_task.completed)
+    .bind(handover_task.id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete(pool: &Pool<Postgres>, id: i32) -> Result<()> {
+    sqlx::query(
+        r#"
+        DELETE FROM
+            handover_task
+        WHERE
+            id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use sqlx::{Error, FromRow, PgConnection, Pool, Postgres, Result};
+
+use super::resignation::decode_status;
+use crate::{
+    calendars::Calendar,
+    consts::idempotency_key::IDEMPOTENCY_KEY_TTL_HOURS,
+    models::idempotency_key::{IdempotencyKey, IdempotencyKeyInput},
+    utils::time::now,
+};
+
+#[derive(FromRow)]
+struct IdempotencyKeyRow {
+    idempotency_key: String,
+    request: String,
+    resignation_id: i32,
+    retirement_date: NaiveDate,
+    remaining_paid_leave_days: i32,
+    calendar: String,
+    status: String,
+    resignation_created_at: NaiveDateTime,
+    created_at: NaiveDateTime,
+    expires_at: NaiveDateTime,
+}
+
+impl TryFrom<IdempotencyKeyRow> for IdempotencyKey {
+    type Error = Error;
+
+    fn try_from(row: IdempotencyKeyRow) -> Result<Self> {
+        Ok(Self {
+            idempotency_key: row.idempotency_key,
+            request: row.request,
+            resignation_id: row.resignation_id,
+            retirement_date: row.retirement_date,
+            remaining_paid_leave_days: row.remaining_paid_leave_days as u32,
+            calendar: Calendar::from_str(&row.calendar).map_err(|e| Error::Decode(e.into()))?,
+            status: decode_status(&row.status)?,
+            resignation_created_at: row.resignation_created_at,
+            created_at: row.created_at,
+            expires_at: row.expires_at,
+        })
+    }
+}
+
+pub async fn fetch(pool: &Pool<Postgres>, idempotency_key: &str) -> Result<Option<IdempotencyKey>> {
+    let idempotency_key = sqlx::query_as::<_, IdempotencyKeyRow>(
+        r#"
+            SELECT
+                idempotency_key, request, resignation_id, retirement_date,
+                remaining_paid_leave_days, calendar, status, resignation_created_at,
+                created_at, expires_at
+            FROM
+                idempotency_key
+            WHERE
+                idempotency_key = $1 AND expires_at > $2
+        "#,
+    )
+    .bind(idempotency_key)
+    .bind(now())
+    .fetch_optional(pool)
+    .await?;
+
+    idempotency_key.map(TryInto::try_into).transpose()
+}
+
+pub async fn insert(conn: &mut PgConnection, input: &IdempotencyKeyInput) -> Result<()> {
+    let now = now();
+    let expires_at = now + TimeDelta::hours(IDEMPOTENCY_KEY_TTL_HOURS);
+    sqlx::query(
+        r#"
+        INSERT INTO
+            idempotency_key (
+                idempotency_key, request, resignation_id, retirement_date,
+                remaining_paid_leave_days, calendar, status, resignation_created_at,
+                created_at, expires_at
+            )
+        VALUES
+            ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+    )
+    .bind(&input.idempotency_key)
+    .bind(&input.request)
+    .bind(input.resignation_id)
+    .bind(input.retirement_date)
+    .bind(input.remaining_paid_leave_days as i32)
+    .bind(input.calendar.code())
+    .bind(input.status.as_str())
+    .bind(input.resignation_created_at)
+    .bind(now)
+    .bind(expires_at)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_expired(pool: &Pool<Postgres>) -> Result<()> {
+    sqlx::query(
+        r#"
+        DELETE FROM
+            idempotency_key
+        WHERE
+            expires_at <= $1
+        "#,
+    )
+    .bind(now())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use std::str::FromStr;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::{Error, FromRow, PgConnection, Pool, Postgres, Result};
+
+use crate::{
+    calendars::Calendar,
+    models::resignation::{Resignation, ResignationInput, ResignationStatus},
+    utils::time::now,
+};
+
+#[derive(FromRow)]
+struct ResignationRow {
+    id: i32,
+    retirement_date: NaiveDate,
+    remaining_paid_leave_days: i32,
+    status: String,
+    calendar: String,
+    created_at: NaiveDateTime,
+}
+
+impl TryFrom<ResignationRow> for Resignation {
+    type Error = Error;
+
+    fn try_from(row: ResignationRow) -> Result<Self> {
+        Ok(Self {
+            id: row.id,
+            retirement_date: row.retirement_date,
+            remaining_paid_leave_days: row.remaining_paid_leave_days as u32,
+            status: decode_status(&row.status)?,
+            calendar: Calendar::from_str(&row.calendar).map_err(|e| Error::Decode(e.into()))?,
+            created_at: row.created_at,
+        })
+    }
+}
+
+pub(super) fn decode_status(status: &str) -> Result<ResignationStatus> {
+    ResignationStatus::from_str(status).map_err(|e| Error::Decode(e.into()))
+}
+
+pub async fn fetch_latest(pool: &Pool<Postgres>) -> Result<Resignation> {
+    let latest_resignation = sqlx::query_as::<_, ResignationRow>(
+        r#"
+            SELECT
+                id, retirement_date, remaining_paid_leave_days, status, calendar, created_at
+            FROM
+                resignation
+            ORDER BY
+                created_at DESC
+            LIMIT 1
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    latest_resignation.try_into()
+}
+
+pub async fn fetch_all(pool: &Pool<Postgres>) -> Result<Vec<Resignation>> {
+    let resignations = sqlx::query_as::<_, ResignationRow>(
+        r#"
+            SELECT
+                id, retirement_date, remaining_paid_leave_days, status, calendar, created_at
+            FROM
+                resignation
+            ORDER BY
+                created_at DESC, id DESC
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    resignations.into_iter().map(TryInto::try_into).collect()
+}
+
+pub async fn fetch(pool: &Pool<Postgres>, id: i32) -> Result<Resignation> {
+    let resignation = sqlx::query_as::<_, ResignationRow>(
+        r#"
+            SELECT
+                id, retirement_date, remaining_paid_leave_days, status, calendar, created_at
+            FROM
+                resignation
+            WHERE
+                id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    resignation.try_into()
+}
+
+pub async fn fetch_for_update(conn: &mut PgConnection, id: i32) -> Result<Resignation> {
+    let resignation = sqlx::query_as::<_, ResignationRow>(
+        r#"
+        SELECT
+            id, retirement_date, remaining_paid_leave_days, status, calendar, created_at
+        FROM
+            resignation
+        WHERE
+            id = $1
+        FOR UPDATE
+        "#,
+    )
+    .bind(id)
+    .fetch_one(conn)
+    .await?;
+
+    resignation.try_into()
+}
+
+pub async fn insert(conn: &mut PgConnection, input: &ResignationInput) -> Result<Resignation> {
+    let resignation = sqlx::query_as::<_, ResignationRow>(
+        r#"
+        INSERT INTO
+            resignation (retirement_date, remaining_paid_leave_days, calendar, created_at)
+        VALUES
+            ($1, $2, $3, $4)
+        RETURNING
+            id, retirement_date, remaining_paid_leave_days, status, calendar, created_at
+        "#,
+    )
+    .bind(input.retirement_date)
+    .bind(input.remaining_paid_leave_days as i32)
+    .bind(input.calendar.code())
+    .bind(now())
+    .fetch_one(conn)
+    .await?;
+
+    resignation.try_into()
+}
+
+pub async fn update(
+    conn: &mut PgConnection,
+    id: i32,
+    input: &ResignationInput,
+) -> Result<Resignation> {
+    let resignation = sqlx::query_as::<_, ResignationRow>(
+        r#"
+        UPDATE
+            resignation
+        SET
+            retirement_date = $1, remaining_paid_leave_days = $2, calendar = $3
+        WHERE
+            id = $4
+        RETURNING
+            id, retirement_date, remaining_paid_leave_days, status, calendar, created_at
+        "#,
+    )
+    .bind(input.retirement_date)
+    .bind(input.remaining_paid_leave_days as i32)
+    .bind(input.calendar.code())
+    .bind(id)
+    .fetch_one(conn)
+    .await?;
+
+    resignation.try_into()
+}
+
+pub async fn update_status(
+    conn: &mut PgConnection,
+    id: i32,
+    status: ResignationStatus,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE
+            resignation
+        SET
+            status = $1
+        WHERE
+            id = $2
+        "#,
+    )
+    .bind(status.as_str())
+    .bind(id)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
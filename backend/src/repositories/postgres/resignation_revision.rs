@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::{Error, FromRow, PgConnection, Pool, Postgres, Result};
+
+use crate::{
+    calendars::Calendar,
+    models::resignation_revision::{ResignationRevision, ResignationRevisionInput},
+    utils::time::now,
+};
+
+#[derive(FromRow)]
+struct ResignationRevisionRow {
+    id: i32,
+    resignation_id: i32,
+    revision: i32,
+    retirement_date: NaiveDate,
+    remaining_paid_leave_days: i32,
+    vacation_start_date: Option<NaiveDate>,
+    calendar: String,
+    created_at: NaiveDateTime,
+}
+
+impl TryFrom<ResignationRevisionRow> for ResignationRevision {
+    type Error = Error;
+
+    fn try_from(row: ResignationRevisionRow) -> Result<Self> {
+        Ok(Self {
+            id: row.id,
+            resignation_id: row.resignation_id,
+            revision: row.revision as u32,
+            retirement_date: row.retirement_date,
+            remaining_paid_leave_days: row.remaining_paid_leave_days as u32,
+            vacation_start_date: row.vacation_start_date,
+            calendar: Calendar::from_str(&row.calendar).map_err(|e| Error::Decode(e.into()))?,
+            created_at: row.created_at,
+        })
+    }
+}
+
+pub async fn fetch(
+    pool: &Pool<Postgres>,
+    resignation_id: i32,
+    revision: u32,
+) -> Result<ResignationRevision> {
+    let resignation_revision = sqlx::query_as::<_, ResignationRevisionRow>(
+        r#"
+            SELECT
+                id, resignation_id, revision, retirement_date,
+                remaining_paid_leave_days, vacation_start_date, calendar, created_at
+            FROM
+                resignation_revision
+            WHERE
+                resignation_id = $1 AND revision = $2
+        "#,
+    )
+    .bind(resignation_id)
+    .bind(revision as i32)
+    .fetch_one(pool)
+    .await?;
+
+    resignation_revision.try_into()
+}
+
+pub async fn fetch_by_resignation_id(
+    pool: &Pool<Postgres>,
+    resignation_id: i32,
+) -> Result<Vec<ResignationRevision>> {
+    let resignation_revisions = sqlx::query_as::<_, ResignationRevisionRow>(
+        r#"
+            SELECT
+                id, resignation_id, revision, retirement_date,
+                remaining_paid_leave_days, vacation_start_date, calendar, created_at
+            FROM
+                resignation_revision
+            WHERE
+                resignation_id = $1
+            ORDER BY
+                revision ASC
+        "#,
+    )
+    .bind(resignation_id)
+    .fetch_all(pool)
+    .await?;
+
+    resignation_revisions
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect()
+}
+
+pub async fn insert(conn: &mut PgConnection, input: &ResignationRevisionInput) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO
+            resignation_revision (
+                resignation_id, revision, retirement_date,
+                remaining_paid_leave_days, vacation_start_date, calendar, created_at
+            )
+        SELECT
+            $1, COALESCE(MAX(revision), 0) + 1, $2, $3, $4, $5, $6
+        FROM
+            resignation_revision
+        WHERE
+            resignation_id = $1
+        "#,
+    )
+    .bind(input.resignation_id)
+    .bind(input.retirement_date)
+    .bind(input.remaining_paid_leave_days as i32)
+    .bind(input.vacation_start_date)
+    .bind(input.calendar.code())
+    .bind(now())
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
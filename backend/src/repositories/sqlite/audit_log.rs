@@ -0,0 +1,86 @@
+use chrono::NaiveDateTime;
+use sqlx::{FromRow, Pool, Result, Sqlite};
+
+use crate::{
+    models::audit_log::{AuditLog, AuditLogFilter, AuditLogInput},
+    utils::time::now,
+};
+
+#[derive(FromRow)]
+struct AuditLogRow {
+    id: i32,
+    operation_name: String,
+    variables: String,
+    claimed_actor: Option<String>,
+    client_ip: Option<String>,
+    succeeded: bool,
+    errors: Option<String>,
+    created_at: NaiveDateTime,
+}
+
+impl From<AuditLogRow> for AuditLog {
+    fn from(row: AuditLogRow) -> Self {
+        Self {
+            id: row.id,
+            operation_name: row.operation_name,
+            variables: row.variables,
+            claimed_actor: row.claimed_actor,
+            client_ip: row.client_ip,
+            succeeded: row.succeeded,
+            errors: row.errors,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub async fn fetch_all(pool: &Pool<Sqlite>, filter: &AuditLogFilter) -> Result<Vec<AuditLog>> {
+    let audit_logs = sqlx::query_as::<_, AuditLogRow>(
+        r#"
+            SELECT
+                id, operation_name, variables, claimed_actor, client_ip, succeeded, errors, created_at
+            FROM
+                audit_log
+            WHERE
+                (?1 IS NULL OR operation_name = ?1)
+                AND (?2 IS NULL OR claimed_actor = ?2)
+                AND (?3 IS NULL OR succeeded = ?3)
+                AND (?4 IS NULL OR created_at >= ?4)
+                AND (?5 IS NULL OR created_at <= ?5)
+            ORDER BY
+                created_at DESC, id DESC
+            LIMIT ?6
+        "#,
+    )
+    .bind(&filter.operation_name)
+    .bind(&filter.claimed_actor)
+    .bind(filter.succeeded)
+    .bind(filter.from)
+    .bind(filter.to)
+    .bind(i64::from(filter.limit))
+    .fetch_all(pool)
+    .await?;
+
+    Ok(audit_logs.into_iter().map(Into::into).collect())
+}
+
+pub async fn insert(pool: &Pool<Sqlite>, input: &AuditLogInput) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO
+            audit_log (operation_name, variables, claimed_actor, client_ip, succeeded, errors, created_at)
+        VALUES
+            (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        "#,
+    )
+    .bind(&input.operation_name)
+    .bind(&input.variables)
+    .bind(&input.claimed_actor)
+    .bind(&input.client_ip)
+    .bind(input.succeeded)
+    .bind(&input.errors)
+    .bind(now())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
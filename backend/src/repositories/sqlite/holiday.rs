@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use sqlx::{Error, FromRow, Pool, Result, Sqlite};
+
+use crate::{
+    calendars::Calendar,
+    models::holiday::{Holiday, HolidaySource},
+};
+
+#[derive(FromRow)]
+struct HolidayRow {
+    holiday_date: NaiveDate,
+    name: String,
+    source: String,
+}
+
+impl TryFrom<HolidayRow> for Holiday {
+    type Error = Error;
+
+    fn try_from(row: HolidayRow) -> Result<Self> {
+        Ok(Self {
+            date: row.holiday_date,
+            name: row.name,
+            source: HolidaySource::from_str(&row.source).map_err(|e| Error::Decode(e.into()))?,
+        })
+    }
+}
+
+pub async fn fetch_all(pool: &Pool<Sqlite>, calendar: Calendar) -> Result<Vec<Holiday>> {
+    let holidays = sqlx::query_as::<_, HolidayRow>(
+        r#"
+            SELECT
+                holiday_date, name, source
+            FROM
+                holiday
+            WHERE
+                calendar = ?1
+            ORDER BY
+                holiday_date ASC
+        "#,
+    )
+    .bind(calendar.code())
+    .fetch_all(pool)
+    .await?;
+
+    holidays.into_iter().map(TryInto::try_into).collect()
+}
+
+/// 同じ日の休日が既にある場合は、名前と種類を置き換える
+pub async fn upsert(pool: &Pool<Sqlite>, calendar: Calendar, holiday: &Holiday) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO
+            holiday (calendar, holiday_date, name, source)
+        VALUES
+            (?1, ?2, ?3, ?4)
+        ON CONFLICT (calendar, holiday_date) DO UPDATE SET
+            name = excluded.name, source = excluded.source
+        "#,
+    )
+    .bind(calendar.code())
+    .bind(holiday.date)
+    .bind(&holiday.name)
+    .bind(holiday.source.as_str())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use chrono::NaiveDateTime;
+use sqlx::{FromRow, Pool, Result, Sqlite};
+
+use crate::{models::persisted_query::PersistedQuery, utils::time::now};
+
+#[derive(FromRow)]
+struct PersistedQueryRow {
+    sha256_hash: String,
+    query: String,
+    created_at: NaiveDateTime,
+}
+
+impl From<PersistedQueryRow> for PersistedQuery {
+    fn from(row: PersistedQueryRow) -> Self {
+        Self {
+            sha256_hash: row.sha256_hash,
+            query: row.query,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub async fn fetch(pool: &Pool<Sqlite>, sha256_hash: &str) -> Result<Option<PersistedQuery>> {
+    let persisted_query = sqlx::query_as::<_, PersistedQueryRow>(
+        r#"
+            SELECT
+                sha256_hash, query, created_at
+            FROM
+                persisted_query
+            WHERE
+                sha256_hash = ?1
+        "#,
+    )
+    .bind(sha256_hash)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(persisted_query.map(Into::into))
+}
+
+pub async fn insert(pool: &Pool<Sqlite>, sha256_hash: &str, query: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO
+            persisted_query (sha256_hash, query, created_at)
+        VALUES
+            (?1, ?2, ?3)
+        "#,
+    )
+    .bind(sha256_hash)
+    .bind(query)
+    .bind(now())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn count(pool: &Pool<Sqlite>) -> Result<i64> {
+    let count = sqlx::query_scalar::<_, i64>(
+        r#"
+            SELECT
+                COUNT(*)
+            FROM
+                persisted_query
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
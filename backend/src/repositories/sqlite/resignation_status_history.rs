@@ -0,0 +1,83 @@
+use chrono::NaiveDateTime;
+use sqlx::{Error, FromRow, Pool, Result, Sqlite, SqliteConnection};
+
+use super::resignation::decode_status;
+use crate::{
+    models::resignation_status_history::{ResignationStatusHistory, ResignationStatusHistoryInput},
+    utils::time::now,
+};
+
+#[derive(FromRow)]
+struct ResignationStatusHistoryRow {
+    id: i32,
+    resignation_id: i32,
+    from_status: String,
+    to_status: String,
+    changed_by: String,
+    comment: Option<String>,
+    changed_at: NaiveDateTime,
+}
+
+impl TryFrom<ResignationStatusHistoryRow> for ResignationStatusHistory {
+    type Error = Error;
+
+    fn try_from(row: ResignationStatusHistoryRow) -> Result<Self> {
+        Ok(Self {
+            id: row.id,
+            resignation_id: row.resignation_id,
+            from_status: decode_status(&row.from_status)?,
+            to_status: decode_status(&row.to_status)?,
+            changed_by: row.changed_by,
+            comment: row.comment,
+            changed_at: row.changed_at,
+        })
+    }
+}
+
+pub async fn fetch_by_resignation_id(
+    pool: &Pool<Sqlite>,
+    resignation_id: i32,
+) -> Result<Vec<ResignationStatusHistory>> {
+    let histories = sqlx::query_as::<_, ResignationStatusHistoryRow>(
+        r#"
+            SELECT
+                id, resignation_id, from_status, to_status, changed_by, comment, changed_at
+            FROM
+                resignation_status_history
+            WHERE
+                resignation_id = ?1
+            ORDER BY
+                changed_at ASC, id ASC
+        "#,
+    )
+    .bind(resignation_id)
+    .fetch_all(pool)
+    .await?;
+
+    histories.into_iter().map(TryInto::try_into).collect()
+}
+
+pub async fn insert(
+    conn: &mut SqliteConnection,
+    input: &ResignationStatusHistoryInput,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO
+            resignation_status_history
+            (resignation_id, from_status, to_status, changed_by, comment, changed_at)
+        VALUES
+            (?1, ?2, ?3, ?4, ?5, ?6)
+        "#,
+    )
+    .bind(input.resignation_id)
+    .bind(input.from_status.as_str())
+    .bind(input.to_status.as_str())
+    .bind(&input.changed_by)
+    .bind(&input.comment)
+    .bind(now())
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDateTime;
+use sqlx::{FromRow, Pool, Result, Sqlite};
+
+use crate::{
+    models::user::{User, UserInput},
+    utils::time::now,
+};
+
+#[derive(FromRow)]
+struct UserRow {
+    id: i32,
+    name: String,
+    is_admin: bool,
+    created_at: NaiveDateTime,
+}
+
+impl From<UserRow> for User {
+    fn from(row: UserRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            is_admin: row.is_admin,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub async fn fetch_all(pool: &Pool<Sqlite>) -> Result<Vec<User>> {
+    let users = sqlx::query_as::<_, UserRow>(
+        r#"
+            SELECT
+                id, name, is_admin, created_at
+            FROM
+                app_user
+            ORDER BY
+                id ASC
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(users.into_iter().map(Into::into).collect())
+}
+
+pub async fn insert(pool: &Pool<Sqlite>, input: &UserInput) -> Result<User> {
+    let user = sqlx::query_as::<_, UserRow>(
+        r#"
+        INSERT INTO
+            app_user (name, is_admin, created_at)
+        VALUES
+            (?1, ?2, ?3)
+        RETURNING
+            id, name, is_admin, created_at
+        "#,
+    )
+    .bind(&input.name)
+    .bind(input.is_admin)
+    .bind(now())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(user.into())
+}
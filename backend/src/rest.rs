@@ -0,0 +1,39 @@
+//! GraphQL を扱えないスクリプトや表計算ソフトのための REST API。
+//! GraphQL と同じ models の関数を呼び、OpenAPI のドキュメントを `/api/openapi.json` で返す。
+
+pub mod error;
+pub mod holidays;
+pub mod openapi;
+pub mod resignations;
+
+use axum::{
+    Router,
+    extract::Request,
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
+};
+
+use crate::infrastructure::{database::DbPool, locale::Locale};
+
+/// エラーメッセージを `Accept-Language` に合わせるため、処理全体を言語のスコープに入れる
+async fn locale_scope(request: Request, next: Next) -> Response {
+    let locale = Locale::from_headers(request.headers());
+
+    locale.scope(next.run(request)).await
+}
+
+pub fn router() -> Router<DbPool> {
+    Router::new()
+        .route(
+            "/resignations",
+            get(resignations::list).post(resignations::create),
+        )
+        .route(
+            "/resignations/{id}/vacation-start-date",
+            get(resignations::vacation_start_date),
+        )
+        .route("/holidays", get(holidays::list))
+        .route("/openapi.json", get(openapi::openapi_json))
+        .layer(middleware::from_fn(locale_scope))
+}
@@ -0,0 +1,75 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::errors::AppError;
+
+/// REST API のエラーのレスポンス。`code` は GraphQL の `extensions.code` と同じ値
+#[derive(Serialize, ToSchema)]
+pub struct ErrorResponse {
+    #[schema(example = "NOT_FOUND")]
+    pub code: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub struct ApiError(pub AppError);
+
+impl<E> From<E> for ApiError
+where
+    E: Into<anyhow::Error>,
+{
+    fn from(error: E) -> Self {
+        let error = error.into();
+        let app_error = AppError::from_anyhow(&error);
+        if let AppError::Internal = app_error {
+            tracing::error!(error = %error, "REST API のリクエストでエラーが発生しました");
+        }
+
+        Self(app_error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status_code = match self.0 {
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::ValidationFailed(_)
+            | AppError::InvalidRequestBody
+            | AppError::InvalidId
+            | AppError::InvalidDateFormat { .. }
+            | AppError::InvalidDateTimeFormat { .. }
+            | AppError::FutureDateRequired { .. }
+            | AppError::NonWorkingRetirementDate { .. }
+            | AppError::IdempotencyKeyTooLong { .. }
+            | AppError::PaidLeaveDaysOutOfRange { .. }
+            | AppError::WorkingDaysBeforeOutOfRange { .. }
+            | AppError::PersistedQueryNotFound
+            | AppError::PersistedQueryNotAllowed
+            | AppError::InvalidPersistedQuery
+            | AppError::InvalidDateRange { .. }
+            | AppError::UnknownCalendar { .. } => StatusCode::BAD_REQUEST,
+            AppError::NoPaidLeave
+            | AppError::InvalidDate
+            | AppError::VacationPeriodTooLong { .. }
+            | AppError::HolidaysNotCovered { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::InvalidStatusTransition { .. } | AppError::IdempotencyKeyConflict => {
+                StatusCode::CONFLICT
+            }
+            AppError::Forbidden => StatusCode::FORBIDDEN,
+            AppError::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            AppError::HolidaySourceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let body = ErrorResponse {
+            code: self.0.code(),
+            message: self.0.to_string(),
+        };
+
+        (status_code, Json(body)).into_response()
+    }
+}
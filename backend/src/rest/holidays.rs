@@ -0,0 +1,125 @@
+use axum::{Extension, Json, extract::Query};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    calendars::Calendar,
+    infrastructure::config::HolidaysConfig,
+    models::holiday::Holiday as HolidayModel,
+    rest::error::{ApiError, ErrorResponse},
+};
+
+#[derive(Serialize, ToSchema)]
+pub struct Holiday {
+    date: NaiveDate,
+    #[schema(example = "元日")]
+    name: String,
+    /// national (国民の祝日) か company (会社の休日)
+    #[schema(example = "national")]
+    source: &'static str,
+}
+
+impl From<HolidayModel> for Holiday {
+    fn from(holiday: HolidayModel) -> Self {
+        Self {
+            date: holiday.date,
+            name: holiday.name,
+            source: holiday.source.as_str(),
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HolidaysParams {
+    /// 休日のカレンダー (JP, US-CA, DE-BY など)。省略した場合は JP
+    calendar: Option<String>,
+}
+
+/// 祝日の一覧。有給の計算と同じ祝日を日付の昇順に並べる
+#[utoipa::path(
+    get,
+    path = "/api/holidays",
+    tag = "holidays",
+    params(HolidaysParams),
+    responses(
+        (status = 200, body = [Holiday]),
+        (status = 400, body = ErrorResponse),
+        (status = 503, body = ErrorResponse),
+    )
+)]
+pub async fn list(
+    Extension(holidays_config): Extension<HolidaysConfig>,
+    Query(params): Query<HolidaysParams>,
+) -> Result<Json<Vec<Holiday>>, ApiError> {
+    let calendar = match params.calendar {
+        Some(code) => code.parse::<Calendar>().map_err(ApiError)?,
+        None => Calendar::default(),
+    };
+    let holidays = HolidayModel::fetch_all(&holidays_config, calendar).await?;
+
+    Ok(Json(holidays.into_iter().map(Into::into).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{
+        infrastructure::database::DbPool,
+        tests::{mocks::server::MockServer, utils::client::client},
+    };
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn list_200(pool: DbPool) {
+        let server = MockServer::new_async().await;
+        let path = "/api/v1/date.json";
+        let json_body = json!({ "2025-01-13": "成人の日", "2025-01-01": "元日" });
+        {
+            let mut srv = server.0.lock().await;
+            srv.mock("GET", path)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(json_body.to_string())
+                .create_async()
+                .await;
+            let (addr, client) = client(pool).await;
+
+            let response = client
+                .request(
+                    Request::builder()
+                        .uri(format!("http://{addr}/api/holidays"))
+                        .header("Host", "localhost")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body: Value = serde_json::from_slice(&bytes).unwrap();
+            let holidays_in_2025 = body
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter(|holiday| holiday["date"].as_str().unwrap().starts_with("2025"))
+                .cloned()
+                .collect::<Vec<_>>();
+            assert_eq!(
+                holidays_in_2025,
+                vec![
+                    json!({ "date": "2025-01-01", "name": "元日", "source": "national" }),
+                    json!({ "date": "2025-01-13", "name": "成人の日", "source": "national" }),
+                ]
+            );
+            srv.reset();
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use axum::Json;
+use utoipa::OpenApi;
+
+use crate::rest::{error, holidays, resignations};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "new-place REST API"),
+    paths(
+        resignations::list,
+        resignations::create,
+        resignations::vacation_start_date,
+        holidays::list,
+    ),
+    components(schemas(
+        resignations::Resignation,
+        resignations::ResignationRequest,
+        resignations::RetirementDateAdjustment,
+        resignations::VacationStartDate,
+        holidays::Holiday,
+        error::ErrorResponse,
+    ))
+)]
+pub struct ApiDoc;
+
+/// ハンドラーの定義から生成した OpenAPI のドキュメント
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn openapi_すべてのパスを含むこと() {
+        let openapi = ApiDoc::openapi();
+
+        let paths = openapi.paths.paths.keys().collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/api/holidays",
+                "/api/resignations",
+                "/api/resignations/{id}/vacation-start-date",
+            ]
+        );
+    }
+}
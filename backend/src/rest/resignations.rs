@@ -0,0 +1,664 @@
+use std::net::SocketAddr;
+
+use axum::{
+    Extension, Json,
+    extract::{ConnectInfo, Path, State, rejection::JsonRejection},
+    http::{HeaderMap, StatusCode},
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    calendars::Calendar,
+    consts::idempotency_key::IDEMPOTENCY_KEY_MAX_LENGTH,
+    errors::AppError,
+    infrastructure::{
+        actor::{Actor, TrustedProxies},
+        config::{HolidaysConfig, PaidLeaveConfig, RetirementDatePolicy},
+        database::DbPool,
+        idempotency_key::IdempotencyKeyHeader,
+    },
+    models::{
+        audit_log::{AuditLog, AuditLogInput, redact},
+        holiday::Holiday,
+        resignation::{
+            Resignation as ResignationModel, ResignationInput,
+            RetirementDateAdjustment as RetirementDateAdjustmentModel,
+        },
+    },
+    rest::error::{ApiError, ErrorResponse},
+    utils::time::now,
+};
+
+#[derive(Serialize, ToSchema)]
+pub struct Resignation {
+    id: i32,
+    retirement_date: NaiveDate,
+    remaining_paid_leave_days: u32,
+    #[schema(example = "JP")]
+    calendar: &'static str,
+    #[schema(example = "draft")]
+    status: &'static str,
+    created_at: NaiveDateTime,
+    /// 登録で退職日をずらした場合だけ返す
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retirement_date_adjustment: Option<RetirementDateAdjustment>,
+}
+
+/// 退職日が休日だったため、設定に従ってずらした結果
+#[derive(Serialize, ToSchema)]
+pub struct RetirementDateAdjustment {
+    #[schema(value_type = String, example = "next_business_day")]
+    policy: RetirementDatePolicy,
+    requested_date: NaiveDate,
+    adjusted_date: NaiveDate,
+}
+
+impl From<RetirementDateAdjustmentModel> for RetirementDateAdjustment {
+    fn from(adjustment: RetirementDateAdjustmentModel) -> Self {
+        Self {
+            policy: adjustment.policy,
+            requested_date: adjustment.requested_date,
+            adjusted_date: adjustment.adjusted_date,
+        }
+    }
+}
+
+impl From<ResignationModel> for Resignation {
+    fn from(resignation: ResignationModel) -> Self {
+        Self {
+            id: resignation.id,
+            retirement_date: resignation.retirement_date,
+            remaining_paid_leave_days: resignation.remaining_paid_leave_days,
+            calendar: resignation.calendar.code(),
+            status: resignation.status.as_str(),
+            created_at: resignation.created_at,
+            retirement_date_adjustment: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ResignationRequest {
+    /// 明日以降の日付
+    retirement_date: NaiveDate,
+    remaining_paid_leave_days: u32,
+    /// 休日のカレンダー (JP, US-CA, DE-BY など)。省略した場合は JP
+    #[schema(example = "US-CA")]
+    calendar: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct VacationStartDate {
+    vacation_start_date: NaiveDate,
+}
+
+/// 退職届の一覧
+#[utoipa::path(
+    get,
+    path = "/api/resignations",
+    tag = "resignations",
+    responses((status = 200, body = [Resignation]))
+)]
+pub async fn list(State(pool): State<DbPool>) -> Result<Json<Vec<Resignation>>, ApiError> {
+    let resignations = ResignationModel::fetch_all(&pool).await?;
+
+    Ok(Json(resignations.into_iter().map(Into::into).collect()))
+}
+
+/// 監査ログに記録する操作の名前
+const CREATE_OPERATION_NAME: &str = "POST /api/resignations";
+
+/// 退職届を登録する。`Idempotency-Key` ヘッダーを付けると、同じキーでの再送では登録したときの退職届を返す。
+/// 登録後に更新されていても、再送で返すのは登録時の内容になる
+#[utoipa::path(
+    post,
+    path = "/api/resignations",
+    tag = "resignations",
+    request_body = ResignationRequest,
+    params(("Idempotency-Key" = Option<String>, Header)),
+    responses(
+        (status = 201, body = Resignation),
+        (status = 400, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 422, body = ErrorResponse),
+        (status = 503, body = ErrorResponse),
+    )
+)]
+pub async fn create(
+    State(pool): State<DbPool>,
+    Extension(holidays_config): Extension<HolidaysConfig>,
+    Extension(paid_leave_config): Extension<PaidLeaveConfig>,
+    Extension(TrustedProxies(trusted_proxies)): Extension<TrustedProxies>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    request: Result<Json<ResignationRequest>, JsonRejection>,
+) -> Result<(StatusCode, Json<Resignation>), ApiError> {
+    // 登録できなかったリクエストも監査ログに記録する
+    let actor = Actor::from_request(&headers, remote_addr, None, &trusted_proxies);
+    let variables = request
+        .as_ref()
+        .ok()
+        .and_then(|Json(request)| serde_json::to_value(request).ok())
+        .map(redact)
+        .unwrap_or_default();
+    let result = insert(
+        &pool,
+        &holidays_config,
+        &paid_leave_config,
+        &headers,
+        request,
+    )
+    .await;
+    let input = AuditLogInput {
+        operation_name: CREATE_OPERATION_NAME.to_string(),
+        variables: variables.to_string(),
+        claimed_actor: actor.claimed_name,
+        client_ip: actor.client_ip,
+        succeeded: result.is_ok(),
+        errors: result
+            .as_ref()
+            .err()
+            .and_then(|ApiError(error)| serde_json::to_string(&[error.to_string()]).ok()),
+    };
+    AuditLog::record(&pool, &input).await;
+
+    result
+}
+
+async fn insert(
+    pool: &DbPool,
+    holidays_config: &HolidaysConfig,
+    paid_leave_config: &PaidLeaveConfig,
+    headers: &HeaderMap,
+    request: Result<Json<ResignationRequest>, JsonRejection>,
+) -> Result<(StatusCode, Json<Resignation>), ApiError> {
+    let Json(request) = request.map_err(|rejection| {
+        tracing::debug!(rejection = %rejection.body_text(), "リクエストの本文を読めませんでした");
+        ApiError(AppError::InvalidRequestBody)
+    })?;
+    let calendar = match request.calendar {
+        Some(code) => code.parse().map_err(ApiError)?,
+        None => Calendar::default(),
+    };
+    let mut input = ResignationInput {
+        retirement_date: request.retirement_date,
+        remaining_paid_leave_days: request.remaining_paid_leave_days,
+        calendar,
+    };
+    input
+        .validate(paid_leave_config, now().date())
+        .map_err(ApiError)?;
+    let idempotency_key = IdempotencyKeyHeader::from_headers(headers);
+    if idempotency_key
+        .as_ref()
+        .is_some_and(|IdempotencyKeyHeader(idempotency_key)| {
+            idempotency_key.len() > IDEMPOTENCY_KEY_MAX_LENGTH
+        })
+    {
+        return Err(ApiError(AppError::IdempotencyKeyTooLong {
+            max: IDEMPOTENCY_KEY_MAX_LENGTH,
+        }));
+    }
+    let holidays = Holiday::fetch_map(holidays_config, calendar).await.ok();
+    let adjustment = input.apply_retirement_date_policy(
+        paid_leave_config.retirement_date_policy,
+        holidays.as_deref(),
+        now().date(),
+    )?;
+    let resignation = match idempotency_key {
+        Some(IdempotencyKeyHeader(idempotency_key)) => {
+            ResignationModel::insert_idempotently(
+                pool,
+                &input,
+                holidays.as_deref(),
+                &idempotency_key,
+            )
+            .await?
+        }
+        None => ResignationModel::insert(pool, &input, holidays.as_deref()).await?,
+    };
+    let mut resignation = Resignation::from(resignation);
+    resignation.retirement_date_adjustment = adjustment.map(Into::into);
+
+    Ok((StatusCode::CREATED, Json(resignation)))
+}
+
+/// 有給休暇の消化を始める日
+#[utoipa::path(
+    get,
+    path = "/api/resignations/{id}/vacation-start-date",
+    tag = "resignations",
+    params(("id" = i32, Path)),
+    responses(
+        (status = 200, body = VacationStartDate),
+        (status = 404, body = ErrorResponse),
+        (status = 422, body = ErrorResponse),
+        (status = 503, body = ErrorResponse),
+    )
+)]
+pub async fn vacation_start_date(
+    State(pool): State<DbPool>,
+    Extension(holidays_config): Extension<HolidaysConfig>,
+    Path(id): Path<String>,
+) -> Result<Json<VacationStartDate>, ApiError> {
+    let id = id
+        .parse::<i32>()
+        .map_err(|_| ApiError(AppError::InvalidId))?;
+    let resignation = ResignationModel::fetch(&pool, id).await?;
+    let holidays = Holiday::fetch_map(&holidays_config, resignation.calendar).await?;
+    let vacation_start_date = resignation.covered_vacation_start_date(&holidays)?;
+
+    Ok(Json(VacationStartDate {
+        vacation_start_date,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use axum::{
+        body::{Body, HttpBody},
+        http::{Request, StatusCode},
+    };
+    use chrono::Days;
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    use crate::{
+        consts::idempotency_key::IDEMPOTENCY_KEY_MAX_LENGTH,
+        infrastructure::{
+            config::{Config, HolidayProvider, RetirementDatePolicy},
+            database::DbPool,
+        },
+        models::audit_log::{AuditLog, AuditLogFilter},
+        tests::{
+            mocks::server::MockServer,
+            utils::client::{client, client_with_config},
+        },
+        utils::time::now,
+    };
+
+    async fn body<B>(response: axum::http::Response<B>) -> Value
+    where
+        B: HttpBody,
+        B::Error: Debug,
+    {
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn list_200(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body(response).await;
+        assert_eq!(body.as_array().unwrap().len(), 2);
+        assert_eq!(body[0]["status"], json!("draft"));
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_201(pool: DbPool) {
+        let server = MockServer::new_async().await;
+        let path = "/api/v1/date.json";
+        let json_body = json!({ "2025-01-01": "休み" });
+        {
+            let mut srv = server.0.lock().await;
+            srv.mock("GET", path)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(json_body.to_string())
+                .create_async()
+                .await;
+            let (addr, client) = client(pool).await;
+            let retirement_date = now().date().checked_add_days(Days::new(30)).unwrap();
+
+            let response = client
+                .request(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("http://{addr}/api/resignations"))
+                        .header("Host", "localhost")
+                        .header("Content-Type", "application/json")
+                        .body(Body::from(
+                            json!({
+                                "retirement_date": retirement_date,
+                                "remaining_paid_leave_days": 5,
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = body(response).await;
+            assert_eq!(body["retirement_date"], json!(retirement_date));
+            assert_eq!(body["status"], json!("draft"));
+            assert_eq!(body["calendar"], json!("JP"));
+            srv.reset();
+        }
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_400_対応していないカレンダーの場合(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+        let retirement_date = now().date().checked_add_days(Days::new(30)).unwrap();
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "retirement_date": retirement_date,
+                            "remaining_paid_leave_days": 5,
+                            "calendar": "XX",
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(body(response).await["code"], json!("VALIDATION_FAILED"));
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_400_冪等キーが長すぎる場合_祝日を取得せずに断ること(
+        pool: DbPool,
+    ) {
+        let server = MockServer::new_async().await;
+        let mut srv = server.0.lock().await;
+        let mock = srv
+            .mock("GET", "/api/v1/date.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "2025-01-01": "休み" }).to_string())
+            .expect(0)
+            .create_async()
+            .await;
+        let (addr, client) = client(pool).await;
+        let retirement_date = now().date().checked_add_days(Days::new(30)).unwrap();
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .header(
+                        "Idempotency-Key",
+                        "a".repeat(IDEMPOTENCY_KEY_MAX_LENGTH + 1),
+                    )
+                    .body(Body::from(
+                        json!({
+                            "retirement_date": retirement_date,
+                            "remaining_paid_leave_days": 5,
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(body(response).await["code"], json!("VALIDATION_FAILED"));
+        mock.assert_async().await;
+        srv.reset();
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_400_退職日が休日で断る設定の場合(pool: DbPool) {
+        let mut config = Config::default();
+        config.holidays.provider = HolidayProvider::Offline;
+        config.paid_leave.retirement_date_policy = RetirementDatePolicy::Reject;
+        let (addr, client) = client_with_config(pool, config).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "retirement_date": "2099-01-01",
+                            "remaining_paid_leave_days": 5,
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(body(response).await["code"], json!("VALIDATION_FAILED"));
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_400_有給日数が上限を超える場合(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+        let retirement_date = now().date().checked_add_days(Days::new(30)).unwrap();
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "retirement_date": retirement_date,
+                            "remaining_paid_leave_days": 41,
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(body(response).await["code"], json!("VALIDATION_FAILED"));
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_400_過去の日付の場合(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .header("Accept-Language", "en")
+                    .body(Body::from(
+                        json!({
+                            "retirement_date": "2020-01-01",
+                            "remaining_paid_leave_days": 5,
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = body(response).await;
+        assert_eq!(body["code"], json!("VALIDATION_FAILED"));
+        assert_eq!(
+            body["message"],
+            json!("Please set a future date (given: Jan 1, 2020)")
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_400_有給日数が負の数の場合_言語に合わせたメッセージを返すこと(
+        pool: DbPool,
+    ) {
+        let (addr, client) = client(pool).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .header("Accept-Language", "ja")
+                    .body(Body::from(
+                        json!({
+                            "retirement_date": "2099-01-01",
+                            "remaining_paid_leave_days": -1,
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = body(response).await;
+        assert_eq!(body["code"], json!("VALIDATION_FAILED"));
+        assert_eq!(
+            body["message"],
+            json!("リクエストの本文を読めません。JSON の形式と各項目の型を確かめてください")
+        );
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn create_断った場合も監査ログを記録すること(pool: DbPool) {
+        let (addr, client) = client(pool.clone()).await;
+        let request = json!({
+            "retirement_date": "2020-01-01",
+            "remaining_paid_leave_days": 5,
+        });
+
+        let response = client
+            .request(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("http://{addr}/api/resignations"))
+                    .header("Host", "localhost")
+                    .header("Content-Type", "application/json")
+                    .header("X-Actor", "yamada")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let filter = AuditLogFilter {
+            limit: 100,
+            ..Default::default()
+        };
+        let audit_logs = AuditLog::fetch_all(&pool, &filter).await.unwrap();
+        assert_eq!(audit_logs.len(), 1);
+        assert_eq!(audit_logs[0].operation_name, "POST /api/resignations");
+        assert_eq!(audit_logs[0].claimed_actor.as_deref(), Some("yamada"));
+        assert!(audit_logs[0].client_ip.is_some());
+        assert!(!audit_logs[0].succeeded);
+        assert!(audit_logs[0].errors.is_some());
+        let variables: Value = serde_json::from_str(&audit_logs[0].variables).unwrap();
+        assert_eq!(variables["retirement_date"], request["retirement_date"]);
+    }
+
+    #[sqlx::test(
+        migrator = "crate::infrastructure::database::MIGRATOR",
+        fixtures("../fixtures/resignation/resignations.sql")
+    )]
+    async fn vacation_start_date_200(pool: DbPool) {
+        let server = MockServer::new_async().await;
+        let path = "/api/v1/date.json";
+        let json_body = json!({
+            "2025-01-01": "休み",
+            "2024-12-31": "休み"
+        });
+        {
+            let mut srv = server.0.lock().await;
+            srv.mock("GET", path)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(json_body.to_string())
+                .create_async()
+                .await;
+            let (addr, client) = client(pool).await;
+
+            let response = client
+                .request(
+                    Request::builder()
+                        .uri(format!(
+                            "http://{addr}/api/resignations/2222/vacation-start-date"
+                        ))
+                        .header("Host", "localhost")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(
+                body(response).await,
+                json!({ "vacation_start_date": "2024-12-24" })
+            );
+            srv.reset();
+        }
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn vacation_start_date_404(pool: DbPool) {
+        let (addr, client) = client(pool).await;
+
+        let response = client
+            .request(
+                Request::builder()
+                    .uri(format!(
+                        "http://{addr}/api/resignations/1/vacation-start-date"
+                    ))
+                    .header("Host", "localhost")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(body(response).await["code"], json!("NOT_FOUND"));
+    }
+}
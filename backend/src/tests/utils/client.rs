@@ -1,16 +1,37 @@
 use axum::body::Body;
 use hyper_util::client::legacy::{Client, connect::HttpConnector};
-use sqlx::MySqlPool;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
 
-use crate::infrastructure::app::app;
+use crate::infrastructure::{app::app, config::Config, database::DbPool};
 
-pub async fn client(pool: MySqlPool) -> (SocketAddr, Client<HttpConnector, Body>) {
+pub const ADMIN_TOKEN: &str = "admin-token";
+
+pub async fn client(pool: DbPool) -> (SocketAddr, Client<HttpConnector, Body>) {
+    client_with_config(
+        pool,
+        Config {
+            admin_token: Some(ADMIN_TOKEN.to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+pub async fn client_with_config(
+    pool: DbPool,
+    config: Config,
+) -> (SocketAddr, Client<HttpConnector, Body>) {
     let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
+    let app = app(pool, config).unwrap();
     tokio::spawn(async move {
-        axum::serve(listener, app(pool)).await.unwrap();
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
     });
     let client = hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
         .build_http();
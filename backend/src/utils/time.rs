@@ -1,7 +1,18 @@
 use chrono::{FixedOffset, NaiveDateTime, Utc};
+use once_cell::sync::OnceCell;
+
+static OFFSET: OnceCell<FixedOffset> = OnceCell::new();
+
+/// 起動時に設定のタイムゾーンを登録する。登録しなければ日本時間になる
+pub fn set_offset(offset: FixedOffset) {
+    let _ = OFFSET.set(offset);
+}
 
 pub fn now() -> NaiveDateTime {
-    let tokyo_offset = FixedOffset::east_opt(9 * 3600).unwrap();
+    let offset = OFFSET
+        .get()
+        .copied()
+        .unwrap_or_else(|| FixedOffset::east_opt(9 * 3600).unwrap());
 
-    Utc::now().with_timezone(&tokyo_offset).naive_local()
+    Utc::now().with_timezone(&offset).naive_local()
 }